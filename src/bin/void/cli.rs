@@ -1,14 +1,186 @@
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg, SubCommand};
+use voidmap::{EXPORT_FORMATS, IMPORT_FORMATS};
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
 const ABOUT: &str = env!("CARGO_PKG_DESCRIPTION");
 
+fn tag_rename<'a>(name: &'a str, about: &'a str) -> App<'a, 'a> {
+    SubCommand::with_name(name)
+        .about(about)
+        .arg(Arg::with_name("OLD").required(true))
+        .arg(Arg::with_name("NEW").required(true))
+        .arg(
+            Arg::with_name("YES")
+                .long("yes")
+                .help("applies the change instead of only counting the nodes it touches"),
+        )
+}
+
+fn node_arg<'a>() -> Arg<'a, 'a> {
+    Arg::with_name("NODE")
+        .help("id or path of a node, like home/work/today")
+        .required(true)
+}
+
 pub fn create<'a>() -> App<'a, 'a> {
     App::new(APP_NAME)
         .version(VERSION)
         .author(AUTHORS)
         .about(ABOUT)
+        .setting(AppSettings::VersionlessSubcommands)
         .arg(Arg::with_name("PATH").takes_value(true).required(false))
+        .subcommand(
+            SubCommand::with_name("add")
+                .about("adds a node, printing its id")
+                .arg(Arg::with_name("TEXT").required(true))
+                .arg(
+                    Arg::with_name("UNDER")
+                        .long("under")
+                        .takes_value(true)
+                        .help("id or path of the parent, defaults to the root"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("ls")
+                .about("lists nodes")
+                .arg(
+                    Arg::with_name("TREE")
+                        .long("tree")
+                        .help("indents children below their parents"),
+                )
+                .arg(
+                    Arg::with_name("QUERY")
+                        .long("query")
+                        .takes_value(true)
+                        .help("only lists nodes containing every word of the query"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("query")
+                .about("lists nodes matching a query like '#tagged=work #open #since=7d'")
+                .arg(Arg::with_name("QUERY").required(true))
+                .arg(
+                    Arg::with_name("SUBTREE")
+                        .long("subtree")
+                        .help("also considers everything below the tagged nodes"),
+                )
+                .arg(
+                    Arg::with_name("JSON")
+                        .long("json")
+                        .help("prints a json array instead of lines of text"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .about("prints a subtree, or everything, in another format")
+                .arg(
+                    Arg::with_name("FORMAT")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&EXPORT_FORMATS)
+                        .possible_value("json")
+                        .default_value("markdown")
+                        .help("json is the whole database, for scripting"),
+                )
+                .arg(
+                    Arg::with_name("FROM")
+                        .long("from")
+                        .takes_value(true)
+                        .help("id or path of the subtree to export, defaults to the root"),
+                )
+                .arg(
+                    Arg::with_name("OUTPUT")
+                        .long("output")
+                        .short("o")
+                        .takes_value(true)
+                        .help("file to write to instead of stdout"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("import")
+                .about("adds what is in a file in another format, printing the new top level ids")
+                .arg(
+                    Arg::with_name("FORMAT")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&IMPORT_FORMATS)
+                        .possible_value("json")
+                        .default_value("markdown")
+                        .help("json replaces the whole database"),
+                )
+                .arg(
+                    Arg::with_name("UNDER")
+                        .long("under")
+                        .takes_value(true)
+                        .help("id or path of the parent, defaults to the root"),
+                )
+                .arg(
+                    Arg::with_name("INPUT")
+                        .required(true)
+                        .help("file to read, or - for stdin"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("done")
+                .about("marks a node as done, or checks off a #habit node")
+                .arg(node_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("rm")
+                .about("moves a node and its children to the trash")
+                .arg(node_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("prints everything about a node")
+                .arg(node_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("log")
+                .about("adds a measurement to a node tagged #metric=<name>")
+                .arg(node_arg())
+                .arg(
+                    Arg::with_name("VALUE")
+                        .required(true)
+                        .allow_hyphen_values(true),
+                )
+                .arg(
+                    Arg::with_name("AT")
+                        .long("at")
+                        .takes_value(true)
+                        .default_value("now")
+                        .help("when it was taken, like 2026-11-01T09:00 or 2h ago"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("series")
+                .about("lists the measurements of a node, oldest first")
+                .arg(node_arg())
+                .arg(
+                    Arg::with_name("SINCE")
+                        .long("since")
+                        .takes_value(true)
+                        .help("leaves out measurements taken before this date"),
+                )
+                .arg(
+                    Arg::with_name("UNTIL")
+                        .long("until")
+                        .takes_value(true)
+                        .help("leaves out measurements taken after this date"),
+                )
+                .arg(
+                    Arg::with_name("JSON")
+                        .long("json")
+                        .help("prints a json array instead of lines of text"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("tag")
+                .about("changes a tag on every node")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(tag_rename("rename", "renames a tag that isn't in use yet"))
+                .subcommand(tag_rename("merge", "renames a tag into one in use")),
+        )
 }
@@ -0,0 +1,350 @@
+use std::{
+    fmt::Write,
+    fs::File,
+    io::{stdin, Read, Write as IoWrite},
+};
+
+use clap::ArgMatches;
+use time::{OffsetDateTime, UtcOffset};
+
+use voidmap::{
+    dateparse, export, import, json_string, screen_from_json, screen_to_json, Measurement, Node,
+    NodeID, Query, Screen,
+};
+
+// reads what a subcommand takes from outside the database. this
+// happens before the database is locked, so that `void export |
+// void import -` doesn't wait on itself.
+pub fn read_input(name: &str, matches: &ArgMatches) -> Result<Option<String>, String> {
+    let path = match (name, matches.value_of("INPUT")) {
+        ("import", Some(path)) => path,
+        _ => return Ok(None),
+    };
+    let mut text = String::new();
+    let read = if path == "-" {
+        stdin().read_to_string(&mut text)
+    } else {
+        File::open(path).and_then(|mut f| f.read_to_string(&mut text))
+    };
+    read.map_err(|e| format!("can't read {}: {}", path, e))?;
+    Ok(Some(text))
+}
+
+// runs a subcommand against the loaded screen and its input, returning
+// true if it changed anything that needs to be saved
+pub fn run(
+    screen: &mut Screen,
+    name: &str,
+    matches: &ArgMatches,
+    input: Option<String>,
+) -> Result<bool, String> {
+    match name {
+        "add" => {
+            let parent_id = match matches.value_of("UNDER") {
+                Some(spec) => resolve(screen, spec)?,
+                None => 0,
+            };
+            let text = matches.value_of("TEXT").unwrap().to_owned();
+            let node_id = screen.add_node(parent_id, text).unwrap();
+            println!("{}", node_id);
+            Ok(true)
+        }
+        "ls" => {
+            let query = matches.value_of("QUERY").unwrap_or("");
+            print!("{}", ls(screen, matches.is_present("TREE"), query));
+            Ok(false)
+        }
+        "query" => {
+            let query = Query::parse(matches.value_of("QUERY").unwrap());
+            if let Some(e) = query.errors.first() {
+                return Err(e.clone());
+            }
+            let node_ids = screen.query(&query, matches.is_present("SUBTREE"));
+            if matches.is_present("JSON") {
+                print!("{}", query_json(screen, &node_ids));
+            } else {
+                print!("{}", query_text(screen, &node_ids));
+            }
+            Ok(false)
+        }
+        "export" => {
+            let format = matches.value_of("FORMAT").unwrap();
+            let data = match (format, matches.value_of("FROM")) {
+                ("json", Some(_)) => return Err("json exports the whole database".to_owned()),
+                ("json", None) => screen_to_json(screen),
+                (_, Some(spec)) => export(screen, resolve(screen, spec)?, format)?,
+                (_, None) => export(screen, 0, format)?,
+            };
+            match matches.value_of("OUTPUT") {
+                Some(path) => File::create(path)
+                    .and_then(|mut f| f.write_all(data.as_bytes()))
+                    .map_err(|e| format!("can't write {}: {}", path, e))?,
+                None => print!("{}", data),
+            }
+            Ok(false)
+        }
+        "import" => {
+            let format = matches.value_of("FORMAT").unwrap();
+            let parent_id = match matches.value_of("UNDER") {
+                Some(_) if format == "json" => {
+                    return Err("json imports replace the whole database".to_owned())
+                }
+                Some(spec) => resolve(screen, spec)?,
+                None => 0,
+            };
+            let text = input.unwrap_or_default();
+            if format == "json" {
+                let imported = screen_from_json(&text)?;
+                screen.max_id = imported.max_id;
+                screen.nodes = imported.nodes;
+                screen.arrows = imported.arrows;
+                screen.trash = imported.trash;
+                screen.trash_days = imported.trash_days;
+                screen.tag_db = imported.tag_db;
+                return Ok(true);
+            }
+            let outlines = import(&text, format)?;
+            for node_id in screen.graft(parent_id, outlines).unwrap() {
+                println!("{}", node_id);
+            }
+            Ok(true)
+        }
+        "done" => {
+            let node_id = resolve(screen, matches.value_of("NODE").unwrap())?;
+            if screen.finish_node(node_id) {
+                Ok(true)
+            } else {
+                eprintln!("{} is already done", node_id);
+                Ok(false)
+            }
+        }
+        "rm" => {
+            let node_id = resolve(screen, matches.value_of("NODE").unwrap())?;
+            if !screen.remove_node(node_id) {
+                return Err("the root can't be removed".to_owned());
+            }
+            Ok(true)
+        }
+        "show" => {
+            let node_id = resolve(screen, matches.value_of("NODE").unwrap())?;
+            print!("{}", show(screen, node_id));
+            Ok(false)
+        }
+        "log" => {
+            let node_id = resolve(screen, matches.value_of("NODE").unwrap())?;
+            let value = matches.value_of("VALUE").unwrap();
+            let value = value
+                .parse()
+                .map_err(|_| format!("not a number: {}", value))?;
+            let time = dateparse(matches.value_of("AT").unwrap()).map_err(|e| e.to_string())?;
+            screen.record_measurement(node_id, time, value)?;
+            Ok(true)
+        }
+        "series" => {
+            let node_id = resolve(screen, matches.value_of("NODE").unwrap())?;
+            let date = |name| {
+                matches
+                    .value_of(name)
+                    .map(|d| dateparse(d).map_err(|e| e.to_string()))
+                    .transpose()
+            };
+            let meta = &screen.nodes[&node_id].meta;
+            let measurements = meta.measurements_between(date("SINCE")?, date("UNTIL")?);
+            if matches.is_present("JSON") {
+                print!("{}", series_json(measurements));
+            } else {
+                for m in measurements {
+                    println!("{}\t{}", format_time(m.time), m.value);
+                }
+            }
+            Ok(false)
+        }
+        "tag" => {
+            let (op, matches) = matches.subcommand();
+            let matches = matches.unwrap();
+            let from = matches.value_of("OLD").unwrap().trim_start_matches('#');
+            let to = matches.value_of("NEW").unwrap().trim_start_matches('#');
+            let merge = op == "merge";
+            if !matches.is_present("YES") {
+                let node_ids = screen.rename_tag_preview(from, to, merge)?;
+                println!(
+                    "would {} #{} into #{} on {} nodes, pass --yes to apply",
+                    op,
+                    from,
+                    to,
+                    node_ids.len()
+                );
+                return Ok(false);
+            }
+            let changed = screen.rename_tag(from, to, merge)?;
+            println!("{}d #{} into #{} on {} nodes", op, from, to, changed);
+            Ok(true)
+        }
+        _ => unreachable!("unknown subcommand {}", name),
+    }
+}
+
+fn resolve(screen: &Screen, spec: &str) -> Result<NodeID, String> {
+    screen
+        .resolve(spec)
+        .ok_or_else(|| format!("no node matches {}", spec))
+}
+
+fn matches_query(node: &Node, query: &str) -> bool {
+    let content = node.content.to_lowercase();
+    query
+        .split_whitespace()
+        .all(|word| content.contains(&word.to_lowercase()))
+}
+
+fn ls(screen: &Screen, tree: bool, query: &str) -> String {
+    let mut out = String::new();
+    let mut stack: Vec<(NodeID, usize)> = screen.nodes[&0]
+        .children
+        .iter()
+        .rev()
+        .map(|&c| (c, 0))
+        .collect();
+    while let Some((node_id, depth)) = stack.pop() {
+        let node = &screen.nodes[&node_id];
+        stack.extend(node.children.iter().rev().map(|&c| (c, depth + 1)));
+        if !matches_query(node, query) {
+            continue;
+        }
+        let status = if node.stricken { "done" } else { "open" };
+        if tree {
+            writeln!(
+                &mut out,
+                "{:indent$}{} {} {}",
+                "",
+                node_id,
+                status,
+                node.content,
+                indent = depth * 2
+            )
+            .unwrap();
+        } else {
+            let path = screen.node_path(node_id);
+            writeln!(&mut out, "{}\t{}\t{}", node_id, status, path).unwrap();
+        }
+    }
+    out
+}
+
+fn format_time(secs: u64) -> String {
+    OffsetDateTime::from_unix_timestamp(secs as i64)
+        .to_offset(UtcOffset::current_local_offset())
+        .format("%Y-%m-%d %H:%M")
+}
+
+fn query_text(screen: &Screen, node_ids: &[NodeID]) -> String {
+    let mut out = String::new();
+    for &node_id in node_ids {
+        let node = &screen.nodes[&node_id];
+        let finish_time = node
+            .meta
+            .finish_time
+            .map(format_time)
+            .unwrap_or_else(|| "-".to_owned());
+        writeln!(
+            &mut out,
+            "{}\t{}\t{}\t{}\t{}",
+            node_id,
+            screen.node_path(node_id),
+            node.content,
+            format_time(node.meta.ctime),
+            finish_time
+        )
+        .unwrap();
+    }
+    out
+}
+
+// times are seconds since the epoch, and finish_time is null for open nodes
+fn query_json(screen: &Screen, node_ids: &[NodeID]) -> String {
+    let objects: Vec<String> = node_ids
+        .iter()
+        .map(|&node_id| {
+            let node = &screen.nodes[&node_id];
+            let finish_time = node
+                .meta
+                .finish_time
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "null".to_owned());
+            format!(
+                "  {{\"id\": {}, \"path\": {}, \"text\": {}, \"ctime\": {}, \"finish_time\": {}}}",
+                node_id,
+                json_string(&screen.node_path(node_id)),
+                json_string(&node.content),
+                node.meta.ctime,
+                finish_time
+            )
+        })
+        .collect();
+    if objects.is_empty() {
+        "[]\n".to_owned()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+// times are seconds since the epoch
+fn series_json(measurements: &[Measurement]) -> String {
+    let objects: Vec<String> = measurements
+        .iter()
+        .map(|m| format!("  {{\"time\": {}, \"value\": {}}}", m.time, m.value))
+        .collect();
+    if objects.is_empty() {
+        "[]\n".to_owned()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn show(screen: &Screen, node_id: NodeID) -> String {
+    let node = &screen.nodes[&node_id];
+    let mut out = String::new();
+    writeln!(&mut out, "id:       {}", node_id).unwrap();
+    writeln!(&mut out, "path:     {}", screen.node_path(node_id)).unwrap();
+    writeln!(&mut out, "text:     {}", node.content).unwrap();
+    writeln!(&mut out, "created:  {}", format_time(node.meta.ctime)).unwrap();
+    writeln!(&mut out, "modified: {}", format_time(node.meta.mtime)).unwrap();
+    if let Some(finish_time) = node.meta.finish_time {
+        writeln!(&mut out, "done:     {}", format_time(finish_time)).unwrap();
+    }
+    if let Some(last) = node.meta.measurements.last() {
+        writeln!(
+            &mut out,
+            "measured: {} times, last {} at {}",
+            node.meta.measurements.len(),
+            last.value,
+            format_time(last.time)
+        )
+        .unwrap();
+    }
+    if let Some(&last) = node.meta.completions.last() {
+        writeln!(
+            &mut out,
+            "checked:  {} times, last at {}",
+            node.meta.completions.len(),
+            format_time(last)
+        )
+        .unwrap();
+    }
+    if !node.children.is_empty() {
+        let children: Vec<String> = node.children.iter().map(|c| c.to_string()).collect();
+        writeln!(&mut out, "children: {}", children.join(" ")).unwrap();
+    }
+    for &(from, to) in &screen.arrows {
+        if from == node_id {
+            writeln!(&mut out, "arrow to: {}", screen.node_path(to)).unwrap();
+        } else if to == node_id {
+            writeln!(&mut out, "arrow from: {}", screen.node_path(from)).unwrap();
+        }
+    }
+    if let Some(ref free_text) = node.free_text {
+        writeln!(&mut out).unwrap();
+        writeln!(&mut out, "{}", free_text).unwrap();
+    }
+    out
+}
@@ -1,8 +1,21 @@
 use fs2::FileExt;
-use std::{ffi::OsString, fs::OpenOptions, io::Read};
+use std::{
+    ffi::{OsStr, OsString},
+    fs::{File, OpenOptions},
+    io::Read,
+    process, thread,
+    time::Duration,
+};
 use voidmap::{deserialize_screen, init_screen_log, Config, Screen};
 
+// how many times a subcommand tries to lock the file, 10ms apart
+const LOCK_ATTEMPTS: usize = 500;
+// subcommands that only read the database, which let go of the
+// lock as soon as it's read
+const READ_ONLY: [&str; 5] = ["ls", "show", "query", "export", "series"];
+
 mod cli;
+mod commands;
 
 fn main() {
     // Initialise the CLI parser
@@ -23,20 +36,36 @@ fn main() {
         })
         .unwrap();
 
-    // load from file if present
-    let mut data = vec![];
-    let mut f = OpenOptions::new()
-        .write(true)
-        .read(true)
-        .create(true)
-        .open(&path)
-        .unwrap();
+    // read what a subcommand takes from elsewhere before locking
+    let input = match matches.subcommand() {
+        (name, Some(sub_matches)) => commands::read_input(name, sub_matches).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        }),
+        _ => None,
+    };
 
-    // exclusively lock the file
-    f.try_lock_exclusive()
-        .unwrap_or_else(|_| panic!("Another `void` process is using this path already!"));
+    // hold the lock from loading until saving, so that nothing else
+    // writes in between. subcommands wait a moment for it, while the
+    // interactive map holds it for as long as it runs.
+    let subcommand = matches.subcommand_name();
+    let lock = if subcommand.is_some() {
+        lock(&path, LOCK_ATTEMPTS).unwrap_or_else(|| {
+            eprintln!("Another `void` process is using this path already!");
+            process::exit(1);
+        })
+    } else {
+        lock(&path, 1).expect("Another `void` process is using this path already!")
+    };
 
-    f.read_to_end(&mut data).unwrap();
+    // load from file if present
+    let mut data = vec![];
+    if let Ok(mut f) = File::open(&path) {
+        f.read_to_end(&mut data).unwrap();
+    }
+    if subcommand.is_some_and(|name| READ_ONLY.contains(&name)) {
+        drop(lock);
+    }
     let saved_screen = deserialize_screen(data).ok();
 
     // Initialise the main working screen
@@ -45,7 +74,7 @@ fn main() {
     screen.work_path = matches
         .value_of("PATH")
         .map(|s| s.into())
-        .or_else(|| Some(path.into_string().unwrap()));
+        .or_else(|| Some(path.clone().into_string().unwrap()));
 
     if let Some(autosave_every) = matches
         .value_of("AUTOSAVE_EVERY")
@@ -54,8 +83,41 @@ fn main() {
         screen.autosave_every = autosave_every;
     }
 
+    if let (name, Some(sub_matches)) = matches.subcommand() {
+        match commands::run(&mut screen, name, sub_matches, input) {
+            Ok(true) => screen.save(),
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
     let config = Config::maybe_parsed_from_env().unwrap();
     screen.config = config;
 
     screen.run();
 }
+
+// the lock is taken on a file next to the database rather than on
+// the database itself, which saving replaces
+fn lock(path: &OsStr, attempts: usize) -> Option<File> {
+    let mut lock_path = path.to_owned();
+    lock_path.push(".lock");
+    let f = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .open(lock_path)
+        .unwrap();
+    for attempt in 0..attempts {
+        if f.try_lock_exclusive().is_ok() {
+            return Some(f);
+        }
+        if attempt + 1 < attempts {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    None
+}
@@ -0,0 +1,178 @@
+use crate::{config, query::SortKey, Action, NodeID, EXPORT_FORMATS, IMPORT_FORMATS};
+
+// ex-style commands that can be typed at the `cmd:` prompt
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cmd {
+    Action(Action),
+    TagAdd(String),
+    TagRemove(String),
+    // from, to, and whether `to` may already be in use
+    TagRename(String, String, bool),
+    // a key, and whether to sort descending
+    Sort(SortKey, bool),
+    Export(String, String),
+    Import(String, String),
+    Goto(NodeID),
+    Set(String, String),
+    EmptyTrash(Option<String>),
+    // a value for the selected metric node, taken now or at a date
+    Log(f64, Option<String>),
+}
+
+const COMMANDS: [&str; 7] = ["tag", "sort", "export", "import", "goto", "set", "log"];
+const TAG_OPS: [&str; 4] = ["add", "rm", "rename", "merge"];
+const SORT_KEYS: [&str; 6] = ["prio", "due", "alpha", "ctime", "mtime", "done"];
+const SETTINGS: [&str; 3] = ["autosave", "undo", "trash_days"];
+const TRASH_OPS: [&str; 1] = ["empty"];
+
+pub fn parse(line: &str) -> Result<Cmd, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [] => Err("empty command".to_owned()),
+        ["tag", "add", tag] => Ok(Cmd::TagAdd(tag.trim_start_matches('#').to_owned())),
+        ["tag", "rm", tag] => Ok(Cmd::TagRemove(tag.trim_start_matches('#').to_owned())),
+        ["tag", op @ "rename", from, to] | ["tag", op @ "merge", from, to] => Ok(Cmd::TagRename(
+            from.trim_start_matches('#').to_owned(),
+            to.trim_start_matches('#').to_owned(),
+            *op == "merge",
+        )),
+        ["tag", ..] => Err("usage: tag add|rm <tag> or tag rename|merge <old> <new>".to_owned()),
+        // the same keys and order as #sort=
+        ["sort", key] => match key.split_once(':') {
+            None => Ok(Cmd::Sort(SortKey::parse(key), false)),
+            Some((key, "desc")) => Ok(Cmd::Sort(SortKey::parse(key), true)),
+            Some((_, order)) => Err(format!("can't sort in {:?} order", order)),
+        },
+        ["sort", ..] => Err(format!("usage: sort {}[:desc]", SORT_KEYS.join("|"))),
+        ["export", format, path @ ..] if !path.is_empty() => {
+            Ok(Cmd::Export((*format).to_owned(), path.join(" ")))
+        }
+        ["export", ..] => Err("usage: export <format> <path>".to_owned()),
+        ["import", format, path @ ..] if !path.is_empty() => {
+            Ok(Cmd::Import((*format).to_owned(), path.join(" ")))
+        }
+        ["import", ..] => Err("usage: import <format> <path>".to_owned()),
+        ["goto", id] => id
+            .parse::<NodeID>()
+            .map(Cmd::Goto)
+            .map_err(|_| format!("invalid node id: {}", id)),
+        ["goto", ..] => Err("usage: goto <id>".to_owned()),
+        ["set", key, value] => Ok(Cmd::Set((*key).to_owned(), (*value).to_owned())),
+        ["set", ..] => Err(format!("usage: set {} <value>", SETTINGS.join("|"))),
+        ["trash", "empty"] => Ok(Cmd::EmptyTrash(None)),
+        ["trash", "empty", age] => Ok(Cmd::EmptyTrash(Some((*age).to_owned()))),
+        ["trash", _, ..] => Err("usage: trash [empty [<age>]]".to_owned()),
+        ["log", value, at @ ..] => match value.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Cmd::Log(
+                value,
+                Some(at.join(" ")).filter(|at| !at.is_empty()),
+            )),
+            _ => Err(format!("not a number: {}", value)),
+        },
+        ["log"] => Err("usage: log <value> [<date>]".to_owned()),
+        ["w"] => Ok(Cmd::Action(Action::Save)),
+        ["q"] => Ok(Cmd::Action(Action::Quit)),
+        [name] => config::to_action(name)
+            .map(Cmd::Action)
+            .ok_or_else(|| format!("unknown command: {}", name)),
+        [name, ..] => Err(format!("unknown command: {}", name)),
+    }
+}
+
+// returns every completed line that the last word of `line` may expand to
+pub fn complete(line: &str, tags: &[String]) -> Vec<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let (done, partial) = if line.is_empty() || line.ends_with(' ') {
+        (&words[..], "")
+    } else {
+        (&words[..words.len() - 1], words[words.len() - 1])
+    };
+
+    let candidates: Vec<&str> = match done {
+        [] => COMMANDS
+            .iter()
+            .cloned()
+            .chain(config::action_names())
+            .collect(),
+        ["tag"] => TAG_OPS.to_vec(),
+        ["tag", _] => tags.iter().map(|t| t.as_str()).collect(),
+        ["tag", "merge", _] => tags.iter().map(|t| t.as_str()).collect(),
+        ["sort"] => SORT_KEYS.to_vec(),
+        ["export"] => EXPORT_FORMATS.to_vec(),
+        ["import"] => IMPORT_FORMATS.to_vec(),
+        ["set"] => SETTINGS.to_vec(),
+        ["trash"] => TRASH_OPS.to_vec(),
+        _ => vec![],
+    };
+
+    let mut prefix = done.join(" ");
+    if !prefix.is_empty() {
+        prefix.push(' ');
+    }
+
+    let mut ret: Vec<String> = candidates
+        .into_iter()
+        .filter(|c| c.starts_with(partial))
+        .map(|c| format!("{}{}", prefix, c))
+        .collect();
+    ret.sort();
+    ret.dedup();
+    ret
+}
+
+#[test]
+fn test_parse_and_complete() {
+    assert_eq!(parse("tag add #work"), Ok(Cmd::TagAdd("work".to_owned())));
+    assert_eq!(
+        parse("tag merge #wrok work"),
+        Ok(Cmd::TagRename("wrok".to_owned(), "work".to_owned(), true))
+    );
+    assert!(parse("tag rename work").is_err());
+    assert_eq!(
+        parse("sort prio:desc"),
+        Ok(Cmd::Sort(SortKey::Tag("prio".to_owned()), true))
+    );
+    assert_eq!(parse("sort done"), Ok(Cmd::Sort(SortKey::Done, false)));
+    assert!(parse("sort done:sideways").is_err());
+    assert_eq!(parse("goto 12"), Ok(Cmd::Goto(12)));
+    assert_eq!(
+        parse("set autosave 10"),
+        Ok(Cmd::Set("autosave".to_owned(), "10".to_owned()))
+    );
+    assert_eq!(
+        parse("toggle_completed"),
+        Ok(Cmd::Action(Action::ToggleCompleted))
+    );
+    assert_eq!(parse("trash"), Ok(Cmd::Action(Action::Trash)));
+    assert_eq!(
+        parse("trash empty 30d"),
+        Ok(Cmd::EmptyTrash(Some("30d".to_owned())))
+    );
+    assert_eq!(
+        parse("log 72.4 2d ago"),
+        Ok(Cmd::Log(72.4, Some("2d ago".to_owned())))
+    );
+    assert!(parse("log nan").is_err());
+    assert!(parse("goto home").is_err());
+    assert!(parse("frobnicate").is_err());
+
+    let tags = vec!["work".to_owned(), "world".to_owned(), "home".to_owned()];
+    assert_eq!(complete("so", &tags), vec!["sort".to_owned()]);
+    assert_eq!(
+        complete("export m", &tags),
+        vec!["export markdown".to_owned()]
+    );
+    assert_eq!(complete("sort p", &tags), vec!["sort prio".to_owned()]);
+    assert_eq!(
+        complete("tag add wo", &tags),
+        vec!["tag add work".to_owned(), "tag add world".to_owned()]
+    );
+    assert_eq!(
+        complete("toggle_c", &tags),
+        vec![
+            "toggle_calendar".to_owned(),
+            "toggle_collapsed".to_owned(),
+            "toggle_completed".to_owned()
+        ]
+    );
+}
@@ -37,58 +37,78 @@ pub enum Action {
     Quit,
     Save,
     ToggleShowLogs,
+    ToggleCalendar,
     EnterCmd,
     FindTask,
     YankPasteNode,
     RaiseSelected,
     LowerSelected,
     Search,
-    UndoDelete,
+    Undo,
+    Redo,
+    Trash,
+    Tags,
+    ExportMarkdown,
     Help,
     SelectParent,
     SelectNextSibling,
     SelectPrevSibling,
 }
 
-fn to_action(input: String) -> Option<Action> {
-    match &*input {
-        "unselect" => Some(Action::UnselectRet),
-        "scroll_up" => Some(Action::ScrollUp),
-        "scroll_down" => Some(Action::ScrollDown),
-        "delete" => Some(Action::DeleteSelected),
-        "select_up" => Some(Action::SelectUp),
-        "select_down" => Some(Action::SelectDown),
-        "select_left" => Some(Action::SelectLeft),
-        "select_right" => Some(Action::SelectRight),
-        "erase" => Some(Action::EraseChar),
-        "create_sibling" => Some(Action::CreateSibling),
-        "create_child" => Some(Action::CreateChild),
-        "create_free_node" => Some(Action::CreateFreeNode),
-        "execute" => Some(Action::ExecSelected),
-        "drill_down" => Some(Action::DrillDown),
-        "pop_up" => Some(Action::PopUp),
-        "jump" => Some(Action::PrefixJump),
-        "toggle_completed" => Some(Action::ToggleCompleted),
-        "toggle_hide_completed" => Some(Action::ToggleHideCompleted),
-        "arrow" => Some(Action::Arrow),
-        "auto_arrange" => Some(Action::AutoArrange),
-        "toggle_collapsed" => Some(Action::ToggleCollapsed),
-        "quit" => Some(Action::Quit),
-        "save" => Some(Action::Save),
-        "toggle_show_logs" => Some(Action::ToggleShowLogs),
-        "enter_command" => Some(Action::EnterCmd),
-        "find_task" => Some(Action::FindTask),
-        "yank_paste_node" => Some(Action::YankPasteNode),
-        "raise_selected" => Some(Action::RaiseSelected),
-        "lower_selected" => Some(Action::LowerSelected),
-        "search" => Some(Action::Search),
-        "undo_delete" => Some(Action::UndoDelete),
-        "help" => Some(Action::Help),
-        "select_parent" => Some(Action::SelectParent),
-        "select_next_sibling" => Some(Action::SelectNextSibling),
-        "select_prev_sibling" => Some(Action::SelectPrevSibling),
-        _ => None,
-    }
+const ACTION_NAMES: &[(&str, Action)] = &[
+    ("unselect", Action::UnselectRet),
+    ("scroll_up", Action::ScrollUp),
+    ("scroll_down", Action::ScrollDown),
+    ("delete", Action::DeleteSelected),
+    ("select_up", Action::SelectUp),
+    ("select_down", Action::SelectDown),
+    ("select_left", Action::SelectLeft),
+    ("select_right", Action::SelectRight),
+    ("erase", Action::EraseChar),
+    ("create_sibling", Action::CreateSibling),
+    ("create_child", Action::CreateChild),
+    ("create_free_node", Action::CreateFreeNode),
+    ("execute", Action::ExecSelected),
+    ("drill_down", Action::DrillDown),
+    ("pop_up", Action::PopUp),
+    ("jump", Action::PrefixJump),
+    ("toggle_completed", Action::ToggleCompleted),
+    ("toggle_hide_completed", Action::ToggleHideCompleted),
+    ("arrow", Action::Arrow),
+    ("auto_arrange", Action::AutoArrange),
+    ("toggle_collapsed", Action::ToggleCollapsed),
+    ("quit", Action::Quit),
+    ("save", Action::Save),
+    ("toggle_show_logs", Action::ToggleShowLogs),
+    ("toggle_calendar", Action::ToggleCalendar),
+    ("enter_command", Action::EnterCmd),
+    ("find_task", Action::FindTask),
+    ("yank_paste_node", Action::YankPasteNode),
+    ("raise_selected", Action::RaiseSelected),
+    ("lower_selected", Action::LowerSelected),
+    ("search", Action::Search),
+    ("undo_delete", Action::Undo),
+    ("undo", Action::Undo),
+    ("redo", Action::Redo),
+    ("trash", Action::Trash),
+    ("tags", Action::Tags),
+    ("export_markdown", Action::ExportMarkdown),
+    ("help", Action::Help),
+    ("select_parent", Action::SelectParent),
+    ("select_next_sibling", Action::SelectNextSibling),
+    ("select_prev_sibling", Action::SelectPrevSibling),
+];
+
+pub fn to_action(input: &str) -> Option<Action> {
+    ACTION_NAMES
+        .iter()
+        .find(|&&(name, _)| name == input)
+        .map(|&(_, action)| action)
+}
+
+// names that can be bound in a keyfile, or typed at the command prompt
+pub fn action_names() -> Vec<&'static str> {
+    ACTION_NAMES.iter().map(|&(name, _)| name).collect()
 }
 
 // Alt and Control must be specified with capital letters C- and A-
@@ -158,13 +178,18 @@ impl Default for Config {
                 (Ctrl('c'), Action::Quit),
                 (Ctrl('x'), Action::Save),
                 (Ctrl('l'), Action::ToggleShowLogs),
+                (Alt('c'), Action::ToggleCalendar),
                 (Ctrl('e'), Action::EnterCmd),
                 (Ctrl('v'), Action::FindTask),
                 (Ctrl('y'), Action::YankPasteNode),
                 (Ctrl('g'), Action::RaiseSelected),
                 (Ctrl('d'), Action::LowerSelected),
                 (Ctrl('u'), Action::Search),
-                (Ctrl('z'), Action::UndoDelete),
+                (Ctrl('z'), Action::Undo),
+                (Alt('z'), Action::Redo),
+                (Ctrl('o'), Action::Trash),
+                (Alt('t'), Action::Tags),
+                (Alt('m'), Action::ExportMarkdown),
                 (Ctrl('?'), Action::Help),
                 (Alt('P'), Action::SelectParent),
                 (Alt('n'), Action::SelectNextSibling),
@@ -218,7 +243,7 @@ impl Config {
             let (raw_action, raw_key) = (parts[0], parts[1]);
 
             let key_opt = to_key(raw_key.to_owned());
-            let action_opt = to_action(raw_action.to_owned());
+            let action_opt = to_action(raw_action);
 
             if key_opt.is_none() || action_opt.is_none() {
                 let e = format!("invalid config at line {}: {}", line_num, line);
@@ -1,63 +1,247 @@
+use std::fmt;
+
 use regex::Regex;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
-pub fn dateparse(input: String) -> Option<u64> {
-    lazy_static! {
-        static ref YEARS: Regex = Regex::new(r"(?P<y>\d+)y").unwrap();
-        static ref MONTHS: Regex = Regex::new(r"(?P<m>\d+)m").unwrap();
-        static ref WEEKS: Regex = Regex::new(r"(?P<w>\d+)w").unwrap();
-        static ref DAYS: Regex = Regex::new(r"(?P<d>\d+)d").unwrap();
-        static ref HOURS: Regex = Regex::new(r"(?P<h>\d+)h").unwrap();
-    }
-
-    let now_in_s = crate::now().as_secs();
-    let min_in_s = 60;
-    let hour_in_s = min_in_s * 60;
-    let day_in_s = hour_in_s * 24;
-    let week_in_s = day_in_s * 7;
-    let month_in_s = day_in_s * 30;
-    let year_in_s = day_in_s * 365;
-
-    let input = input.trim();
-
-    if let Some(cap) = YEARS.captures(input) {
-        if let Some(years_raw) = cap.get(1) {
-            if let Ok(years) = years_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - years * year_in_s);
-            }
+const MINUTE: u64 = 60;
+const HOUR: u64 = MINUTE * 60;
+const DAY: u64 = HOUR * 24;
+const WEEK: u64 = DAY * 7;
+const MONTH: u64 = DAY * 30;
+const YEAR: u64 = DAY * 365;
+
+const WEEKDAYS: [&[&str]; 7] = [
+    &["mon", "monday"],
+    &["tue", "tues", "tuesday"],
+    &["wed", "wednesday"],
+    &["thu", "thur", "thurs", "thursday"],
+    &["fri", "friday"],
+    &["sat", "saturday"],
+    &["sun", "sunday"],
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateError {
+    Empty,
+    // nothing we know how to read
+    Unknown(String),
+    // reads like a date, but there is no such day or time
+    Invalid(String),
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DateError::Empty => write!(f, "no date given"),
+            DateError::Unknown(ref input) => write!(f, "can't read {:?} as a date", input),
+            DateError::Invalid(ref input) => write!(f, "{:?} is not a valid date", input),
         }
     }
+}
 
-    if let Some(cap) = MONTHS.captures(input) {
-        if let Some(months_raw) = cap.get(1) {
-            if let Ok(months) = months_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - months * month_in_s);
-            }
-        }
+// a time in seconds since the epoch, from any of
+//   durations, which count back from now: `7d`, `1w2d`, `3 hours`
+//   offsets either way: `+3d`, `in 2w`, `-1m`, `2d ago`
+//   local dates and times: `2026-11-01`, `2026-11-01T09:00`
+//   keywords: `now`, `today`, `tomorrow`, `yesterday`, `monday`,
+//     and `eow`, `eom` or `eoy` for the last day of the period.
+//     weekdays are the most recent one, today included
+pub fn dateparse(input: &str) -> Result<u64, DateError> {
+    parse(input, crate::now().as_secs(), false)
+}
+
+// like dateparse, except that bare durations and weekdays count
+// forward, as due dates lie ahead: `3d` is three days from now
+pub fn dateparse_due(input: &str) -> Result<u64, DateError> {
+    parse(input, crate::now().as_secs(), true)
+}
+
+fn parse(input: &str, now: u64, ahead: bool) -> Result<u64, DateError> {
+    lazy_static! {
+        static ref RE_DATE: Regex =
+            Regex::new(r"^(\d{4})-(\d{1,2})-(\d{1,2})(?:[t ](\d{1,2}):(\d{2})(?::(\d{2}))?)?$")
+                .unwrap();
     }
 
-    if let Some(cap) = WEEKS.captures(input) {
-        if let Some(weeks_raw) = cap.get(1) {
-            if let Ok(weeks) = weeks_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - weeks * week_in_s);
-            }
-        }
+    let original = input.trim();
+    if original.is_empty() {
+        return Err(DateError::Empty);
     }
+    let unknown = || DateError::Unknown(original.to_owned());
+    let invalid = || DateError::Invalid(original.to_owned());
+    let input = original.to_lowercase();
 
-    if let Some(cap) = DAYS.captures(input) {
-        if let Some(days_raw) = cap.get(1) {
-            if let Ok(days) = days_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - days * day_in_s);
-            }
-        }
+    let (forward, span) = if let Some(rest) = input.strip_prefix('+') {
+        (Some(true), rest)
+    } else if let Some(rest) = input.strip_prefix("in ") {
+        (Some(true), rest)
+    } else if let Some(rest) = input.strip_prefix('-') {
+        (Some(false), rest)
+    } else if let Some(rest) = input.strip_suffix(" ago") {
+        (Some(false), rest)
+    } else {
+        (None, input.as_str())
+    };
+    if let Some(secs) = duration(span) {
+        return if forward.unwrap_or(ahead) {
+            now.checked_add(secs).ok_or_else(invalid)
+        } else {
+            now.checked_sub(secs).ok_or_else(invalid)
+        };
+    } else if forward.is_some() {
+        return Err(unknown());
     }
 
-    if let Some(cap) = HOURS.captures(input) {
-        if let Some(hours_raw) = cap.get(1) {
-            if let Ok(hours) = hours_raw.as_str().parse::<u64>() {
-                return Some(now_in_s - hours * hour_in_s);
-            }
+    let today = local_date(now);
+    let date = match input.as_str() {
+        "now" => return Ok(now),
+        "today" => today,
+        "tomorrow" => today.next_day(),
+        "yesterday" => today.previous_day(),
+        "eow" => today + Duration::days(6 - i64::from(today.weekday().number_days_from_monday())),
+        "eom" => {
+            let (year, month) = match today.month() {
+                12 => (today.year() + 1, 1),
+                month => (today.year(), month + 1),
+            };
+            Date::try_from_ymd(year, month, 1)
+                .map_err(|_| invalid())?
+                .previous_day()
         }
+        "eoy" => Date::try_from_ymd(today.year(), 12, 31).map_err(|_| invalid())?,
+        word => match WEEKDAYS.iter().position(|names| names.contains(&word)) {
+            // the next one to come, a week out if it's today, or
+            // the last one to pass, today included
+            Some(weekday) => {
+                let today_n = i64::from(today.weekday().number_days_from_monday());
+                if ahead {
+                    today + Duration::days((weekday as i64 - today_n + 6).rem_euclid(7) + 1)
+                } else {
+                    today - Duration::days((today_n - weekday as i64).rem_euclid(7))
+                }
+            }
+            None => {
+                let caps = RE_DATE.captures(word).ok_or_else(unknown)?;
+                // the regex only lets through a few digits, so these fit
+                let number = |i: usize| caps.get(i).map_or(0, |m| m.as_str().parse().unwrap());
+                let date = Date::try_from_ymd(number(1), number(2) as u8, number(3) as u8)
+                    .map_err(|_| invalid())?;
+                let time = Time::try_from_hms(number(4) as u8, number(5) as u8, number(6) as u8)
+                    .map_err(|_| invalid())?;
+                return local_timestamp(date, time).ok_or_else(invalid);
+            }
+        },
+    };
+    local_timestamp(date, Time::midnight()).ok_or_else(invalid)
+}
+
+// the total of one or more amounts with units, like `1w2d` or `3 days`
+pub fn duration(text: &str) -> Option<u64> {
+    lazy_static! {
+        static ref RE_PART: Regex = Regex::new(r"^\s*(\d+)\s*([a-z]+)").unwrap();
+    }
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total: u64 = 0;
+    while !rest.is_empty() {
+        let caps = RE_PART.captures(rest)?;
+        let n: u64 = caps[1].parse().ok()?;
+        let unit = match &caps[2] {
+            "y" | "year" | "years" => YEAR,
+            "m" | "mo" | "month" | "months" => MONTH,
+            "w" | "week" | "weeks" => WEEK,
+            "d" | "day" | "days" => DAY,
+            "h" | "hour" | "hours" => HOUR,
+            "min" | "mins" | "minute" | "minutes" => MINUTE,
+            _ => return None,
+        };
+        total = total.checked_add(n.checked_mul(unit)?)?;
+        rest = rest[caps.get(0).unwrap().end()..].trim_start();
+    }
+    Some(total)
+}
+
+fn local_date(secs: u64) -> Date {
+    let utc = OffsetDateTime::from_unix_timestamp(secs as i64);
+    utc.to_offset(UtcOffset::local_offset_at(utc)).date()
+}
+
+// seconds since the epoch for a date and time in the local timezone
+pub fn local_timestamp(date: Date, time: Time) -> Option<u64> {
+    let datetime = PrimitiveDateTime::new(date, time);
+    let offset = UtcOffset::local_offset_at(datetime.assume_utc());
+    let secs = datetime.assume_offset(offset).timestamp();
+    if secs < 0 {
+        None
+    } else {
+        Some(secs as u64)
     }
+}
+
+#[test]
+fn test_dateparse() {
+    let now = local_timestamp(Date::try_from_ymd(2026, 10, 14).unwrap(), Time::midnight()).unwrap()
+        + 9 * HOUR;
+    let today = now - 9 * HOUR;
+    let parse = |input| parse(input, now, false);
+    // days aren't all 24 hours long across a change of daylight saving time
+    let midnight = |year, month, day| {
+        local_timestamp(
+            Date::try_from_ymd(year, month, day).unwrap(),
+            Time::midnight(),
+        )
+    };
+
+    assert_eq!(parse("7d"), Ok(now - 7 * DAY));
+    assert_eq!(parse("1w2d"), Ok(now - 9 * DAY));
+    assert_eq!(parse("1 week 2 days"), Ok(now - 9 * DAY));
+    assert_eq!(parse("2d ago"), Ok(now - 2 * DAY));
+    assert_eq!(parse("+3d"), Ok(now + 3 * DAY));
+    assert_eq!(parse("in 2w"), Ok(now + 2 * WEEK));
+    assert_eq!(parse("-1h"), Ok(now - HOUR));
+    assert_eq!(self::parse("3d", now, true), Ok(now + 3 * DAY));
+
+    assert_eq!(parse("now"), Ok(now));
+    assert_eq!(parse("Today"), Ok(today));
+    assert_eq!(parse("tomorrow").ok(), midnight(2026, 10, 15));
+    assert_eq!(parse("yesterday").ok(), midnight(2026, 10, 13));
+    // the 14th is a wednesday. due dates are the next one to come,
+    // and anything else the last one to pass
+    let due = |input| self::parse(input, now, true);
+    assert_eq!(due("wed").ok(), midnight(2026, 10, 21));
+    assert_eq!(due("monday").ok(), midnight(2026, 10, 19));
+    assert_eq!(parse("wed"), Ok(today));
+    assert_eq!(parse("monday").ok(), midnight(2026, 10, 12));
+    assert_eq!(parse("thu").ok(), midnight(2026, 10, 8));
+    assert_eq!(parse("eow").ok(), midnight(2026, 10, 18));
+    assert_eq!(parse("eom").ok(), midnight(2026, 10, 31));
+    assert_eq!(parse("eoy").ok(), midnight(2026, 12, 31));
+
+    assert_eq!(parse("2026-10-16"), Ok(today + 2 * DAY));
+    assert_eq!(
+        parse("2026-10-16T09:30"),
+        Ok(today + 2 * DAY + 9 * HOUR + 30 * MINUTE)
+    );
+    assert_eq!(
+        parse("2026-10-16 09:30:05"),
+        Ok(today + 2 * DAY + 9 * HOUR + 30 * MINUTE + 5)
+    );
 
-    None
+    assert_eq!(parse(" "), Err(DateError::Empty));
+    assert_eq!(parse("soon"), Err(DateError::Unknown("soon".to_owned())));
+    assert_eq!(parse("+soon"), Err(DateError::Unknown("+soon".to_owned())));
+    assert_eq!(parse("3x"), Err(DateError::Unknown("3x".to_owned())));
+    assert_eq!(
+        parse("2026-02-30"),
+        Err(DateError::Invalid("2026-02-30".to_owned()))
+    );
+    assert_eq!(
+        parse("2026-10-16T25:00"),
+        Err(DateError::Invalid("2026-10-16T25:00".to_owned()))
+    );
+    // before the epoch
+    assert!(parse("50y").is_ok());
+    assert!(parse("100y").is_err());
 }
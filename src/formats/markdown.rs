@@ -0,0 +1,234 @@
+use std::fmt::Write;
+
+use regex::Regex;
+
+use crate::{formats::Outline, NodeID, Screen};
+
+// nested bullet lists, with checkboxes for tasks, free text as
+// indented paragraphs and arrows as footnotes. the root itself
+// is left out, so exporting it yields every top level node.
+pub fn to_markdown(screen: &Screen, node_id: NodeID) -> String {
+    let mut out = String::new();
+    let mut footnotes = vec![];
+    if node_id == 0 {
+        for &child in &screen.nodes[&0].children {
+            write_node(screen, child, 0, &mut out, &mut footnotes);
+        }
+    } else {
+        write_node(screen, node_id, 0, &mut out, &mut footnotes);
+    }
+
+    if !footnotes.is_empty() {
+        if !out.ends_with("\n\n") {
+            out.push('\n');
+        }
+        for (i, &to) in footnotes.iter().enumerate() {
+            writeln!(&mut out, "[^{}]: → {}", i + 1, screen.node_path(to)).unwrap();
+        }
+    }
+    out
+}
+
+fn write_node(
+    screen: &Screen,
+    node_id: NodeID,
+    depth: usize,
+    out: &mut String,
+    footnotes: &mut Vec<NodeID>,
+) {
+    let node = &screen.nodes[&node_id];
+    let in_task = super::is_task(screen, node_id);
+    let indent = "  ".repeat(depth);
+    let checkbox = if node.stricken {
+        "[x] "
+    } else if in_task {
+        "[ ] "
+    } else {
+        ""
+    };
+    write!(out, "{}- {}{}", indent, checkbox, node.content).unwrap();
+    for &(from, to) in &screen.arrows {
+        if from == node_id {
+            footnotes.push(to);
+            write!(out, " [^{}]", footnotes.len()).unwrap();
+        }
+    }
+    out.push('\n');
+
+    if let Some(ref free_text) = node.free_text {
+        out.push('\n');
+        for line in free_text.lines() {
+            if line.trim().is_empty() {
+                out.push('\n');
+            } else {
+                writeln!(out, "{}  {}", indent, line).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+
+    for &child in &node.children {
+        write_node(screen, child, depth + 1, out, footnotes);
+    }
+}
+
+// headings and list items become nodes, nested by heading level and
+// indentation, and any other text lands in the free text of the
+// closest item above it. checked items are marked as done.
+pub fn from_markdown(text: &str) -> Vec<Outline> {
+    lazy_static! {
+        static ref RE_HEADING: Regex = Regex::new(r"^(#{1,6})\s+(.*?)[\s#]*$").unwrap();
+        static ref RE_ITEM: Regex =
+            Regex::new(r"^(\s*)(?:[-*+]|\d+[.)])\s+(?:\[([ xX])\]\s+)?(.*)$").unwrap();
+    }
+
+    // open nodes, keyed so that headings sort by level and before
+    // any list item, and list items sort by their indentation
+    let mut stack: Vec<((bool, usize), Outline)> = vec![];
+    let mut roots = vec![];
+    let mut after_blank = false;
+    for line in text.lines() {
+        let line = line.replace('\t', "    ");
+        if let Some(caps) = RE_HEADING.captures(&line) {
+            let outline = Outline::new(&caps[2]);
+            push(&mut stack, &mut roots, (false, caps[1].len()), outline);
+        } else if let Some(caps) = RE_ITEM.captures(&line) {
+            let mut outline = Outline::new(&caps[3]);
+            if caps.get(2).is_some_and(|c| c.as_str() != " ") {
+                outline.node.stricken = true;
+                outline.node.meta.finish();
+            }
+            push(&mut stack, &mut roots, (true, caps[1].len()), outline);
+        } else if line.trim().is_empty() {
+            after_blank = true;
+            continue;
+        } else if let Some((_, outline)) = stack.last_mut() {
+            let free_text = outline.node.free_text.get_or_insert_with(String::new);
+            if !free_text.is_empty() {
+                free_text.push_str(if after_blank { "\n\n" } else { "\n" });
+            }
+            free_text.push_str(line.trim());
+        } else {
+            roots.push(Outline::new(line.trim()));
+        }
+        after_blank = false;
+    }
+    while !stack.is_empty() {
+        pop(&mut stack, &mut roots);
+    }
+    roots
+}
+
+fn push(
+    stack: &mut Vec<((bool, usize), Outline)>,
+    roots: &mut Vec<Outline>,
+    key: (bool, usize),
+    outline: Outline,
+) {
+    while stack.last().is_some_and(|&(k, _)| k >= key) {
+        pop(stack, roots);
+    }
+    stack.push((key, outline));
+}
+
+fn pop(stack: &mut Vec<((bool, usize), Outline)>, roots: &mut Vec<Outline>) {
+    if let Some((_, outline)) = stack.pop() {
+        match stack.last_mut() {
+            Some((_, parent)) => parent.children.push(outline),
+            None => roots.push(outline),
+        }
+    }
+}
+
+#[test]
+fn test_to_markdown() {
+    let mut screen = Screen::default();
+    let project = screen.add_node(0, "project #task".to_owned()).unwrap();
+    let first = screen.add_node(project, "first".to_owned()).unwrap();
+    let second = screen.add_node(project, "second".to_owned()).unwrap();
+    let notes = screen.add_node(0, "notes".to_owned()).unwrap();
+    screen.finish_node(first);
+    screen.nodes.get_mut(&notes).unwrap().free_text = Some("some\n\nthoughts".to_owned());
+    screen.arrows.push((second, notes));
+
+    assert_eq!(
+        to_markdown(&screen, 0),
+        "- [ ] project #task
+  - [x] first
+  - [ ] second [^1]
+- notes
+
+  some
+
+  thoughts
+
+[^1]: → home/notes
+"
+    );
+    assert_eq!(
+        to_markdown(&screen, second),
+        "- [ ] second [^1]\n\n[^1]: → home/notes\n"
+    );
+}
+
+#[test]
+fn test_only_task_tags_make_tasks() {
+    let mut screen = Screen::default();
+    let chores = screen.add_node(0, "chores #tasks".to_owned()).unwrap();
+    screen.add_node(chores, "#q=#task".to_owned()).unwrap();
+    let work = screen.add_node(0, "work #task/client".to_owned()).unwrap();
+    let call = screen.add_node(work, "call".to_owned()).unwrap();
+
+    assert_eq!(
+        to_markdown(&screen, 0),
+        "- chores #tasks
+  - #q=#task
+- [ ] work #task/client
+  - [ ] call
+"
+    );
+    assert_eq!(to_markdown(&screen, call), "- [ ] call\n");
+}
+
+#[test]
+fn test_from_markdown() {
+    let outlines = from_markdown(
+        "intro
+# Plans
+## Today
+- [x] write
+  it went well
+
+  mostly
+* [ ] review
+  1. first
+  2) second
+# Later
+- sleep
+",
+    );
+    let content = |o: &Outline| o.node.content.clone();
+    assert_eq!(
+        outlines.iter().map(content).collect::<Vec<_>>(),
+        vec!["intro", "Plans", "Later"]
+    );
+    let today = &outlines[1].children[0];
+    assert_eq!(content(today), "Today");
+    assert_eq!(
+        today.children.iter().map(content).collect::<Vec<_>>(),
+        vec!["write", "review"]
+    );
+    let write = &today.children[0];
+    assert!(write.node.stricken && write.node.meta.finish_time.is_some());
+    assert_eq!(
+        write.node.free_text,
+        Some("it went well\n\nmostly".to_owned())
+    );
+    let review = &today.children[1];
+    assert!(!review.node.stricken);
+    assert_eq!(
+        review.children.iter().map(content).collect::<Vec<_>>(),
+        vec!["first", "second"]
+    );
+    assert_eq!(content(&outlines[2].children[0]), "sleep");
+}
@@ -0,0 +1,66 @@
+mod markdown;
+mod opml;
+mod org;
+
+use crate::{Node, NodeID, Screen};
+
+pub use self::{
+    markdown::{from_markdown, to_markdown},
+    opml::{from_opml, to_opml},
+    org::{from_org, to_org, OrgPriorities},
+};
+
+// formats a subtree can be exported to or imported from, by
+// the names used at the command prompt and on the command line
+pub const EXPORT_FORMATS: [&str; 3] = ["markdown", "opml", "org"];
+pub const IMPORT_FORMATS: [&str; 3] = ["markdown", "opml", "org"];
+
+// an imported subtree, before its nodes are given ids. only
+// the fields an importer knows about need to be filled in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outline {
+    pub node: Node,
+    pub children: Vec<Outline>,
+}
+
+impl Outline {
+    pub fn new(content: &str) -> Outline {
+        Outline {
+            node: Node {
+                content: content.to_owned(),
+                ..Node::default()
+            },
+            children: vec![],
+        }
+    }
+}
+
+// a node is a task when it or one of its ancestors, even above the
+// exported node, is tagged #task
+fn is_task(screen: &Screen, node_id: NodeID) -> bool {
+    screen
+        .tag_db
+        .effective_tags(node_id, &screen.nodes)
+        .contains("task")
+}
+
+pub fn export(screen: &Screen, node_id: NodeID, format: &str) -> Result<String, String> {
+    if !screen.nodes.contains_key(&node_id) {
+        return Err(format!("no node with id {}", node_id));
+    }
+    match format {
+        "markdown" | "md" => Ok(to_markdown(screen, node_id)),
+        "opml" => Ok(to_opml(screen, node_id)),
+        "org" => Ok(to_org(screen, node_id, &OrgPriorities::from_env()?)),
+        _ => Err(format!("unknown export format: {}", format)),
+    }
+}
+
+pub fn import(text: &str, format: &str) -> Result<Vec<Outline>, String> {
+    match format {
+        "markdown" | "md" => Ok(from_markdown(text)),
+        "opml" => from_opml(text),
+        "org" => Ok(from_org(text, &OrgPriorities::from_env()?)),
+        _ => Err(format!("unknown import format: {}", format)),
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt::Write;
+
+use regex::Regex;
+
+use crate::{formats::Outline, Node, NodeID, Screen};
+
+// an OPML 2.0 document. like the markdown export, exporting the
+// root yields its children as the top level outlines. void specific
+// fields are kept in attributes prefixed with `void_`.
+pub fn to_opml(screen: &Screen, node_id: NodeID) -> String {
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<opml version=\"2.0\">\n");
+    out.push_str("  <head>\n");
+    let title = escape(&screen.nodes[&node_id].content);
+    writeln!(&mut out, "    <title>{}</title>", title).unwrap();
+    out.push_str("  </head>\n");
+    out.push_str("  <body>\n");
+    if node_id == 0 {
+        for &child in &screen.nodes[&0].children {
+            write_outline(screen, child, 2, &mut out);
+        }
+    } else {
+        write_outline(screen, node_id, 2, &mut out);
+    }
+    out.push_str("  </body>\n");
+    out.push_str("</opml>\n");
+    out
+}
+
+fn write_outline(screen: &Screen, node_id: NodeID, depth: usize, out: &mut String) {
+    let node = &screen.nodes[&node_id];
+    let indent = "  ".repeat(depth);
+    write!(out, "{}<outline text=\"{}\"", indent, escape(&node.content)).unwrap();
+    if let Some(ref free_text) = node.free_text {
+        write!(out, " _note=\"{}\"", escape(free_text)).unwrap();
+    }
+    if node.stricken {
+        out.push_str(" _complete=\"true\"");
+    }
+    if node.collapsed {
+        out.push_str(" void_collapsed=\"true\"");
+    }
+    write!(out, " void_ctime=\"{}\"", node.meta.ctime).unwrap();
+    write!(out, " void_mtime=\"{}\"", node.meta.mtime).unwrap();
+    if let Some(finish_time) = node.meta.finish_time {
+        write!(out, " void_finish_time=\"{}\"", finish_time).unwrap();
+    }
+    if let Some(due) = node.meta.due {
+        write!(out, " void_due=\"{}\"", due).unwrap();
+    }
+    if !node.meta.tags.is_empty() {
+        let mut tags: Vec<String> = node
+            .meta
+            .tags
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        tags.sort();
+        write!(out, " void_tags=\"{}\"", escape(&tags.join("\n"))).unwrap();
+    }
+
+    if node.children.is_empty() {
+        out.push_str("/>\n");
+    } else {
+        out.push_str(">\n");
+        for &child in &node.children {
+            write_outline(screen, child, depth + 1, out);
+        }
+        writeln!(out, "{}</outline>", indent).unwrap();
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut ret = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => ret.push_str("&amp;"),
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            '"' => ret.push_str("&quot;"),
+            '\n' => ret.push_str("&#10;"),
+            '\t' => ret.push_str("&#9;"),
+            c => ret.push(c),
+        }
+    }
+    ret
+}
+
+fn unescape(text: &str) -> String {
+    lazy_static! {
+        static ref RE_ENTITY: Regex = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+    }
+    RE_ENTITY
+        .replace_all(text, |caps: &regex::Captures| {
+            let entity = &caps[1];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32),
+                _ => entity[1..].parse().ok().and_then(std::char::from_u32),
+            };
+            c.map(|c| c.to_string())
+                .unwrap_or_else(|| caps[0].to_owned())
+        })
+        .into_owned()
+}
+
+// reads every <outline> element, ignoring everything else
+pub fn from_opml(text: &str) -> Result<Vec<Outline>, String> {
+    lazy_static! {
+        static ref RE_COMMENT: Regex = Regex::new(r"(?s)<!--.*?-->").unwrap();
+        static ref RE_TAG: Regex = Regex::new(
+            r#"<(/?)([A-Za-z_][\w:.-]*)((?:\s+[\w:.-]+\s*=\s*(?:"[^"]*"|'[^']*'))*)\s*(/?)>"#
+        )
+        .unwrap();
+        static ref RE_ATTR: Regex =
+            Regex::new(r#"([\w:.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+    }
+
+    let text = RE_COMMENT.replace_all(text, "");
+    let mut stack: Vec<Outline> = vec![];
+    let mut roots = vec![];
+    for caps in RE_TAG.captures_iter(&text) {
+        if &caps[2] != "outline" {
+            continue;
+        }
+        let closing = !caps[1].is_empty();
+        let self_closing = !caps[4].is_empty();
+        let outline = if closing {
+            stack.pop().ok_or("unexpected </outline>")?
+        } else {
+            let mut node = Node::default();
+            for attr in RE_ATTR.captures_iter(&caps[3]) {
+                let value = unescape(attr.get(2).or_else(|| attr.get(3)).unwrap().as_str());
+                set_attribute(&mut node, &attr[1], value)?;
+            }
+            if node.stricken && node.meta.finish_time.is_none() {
+                node.meta.finish();
+            }
+            let outline = Outline {
+                node,
+                children: vec![],
+            };
+            if !self_closing {
+                stack.push(outline);
+                continue;
+            }
+            outline
+        };
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(outline),
+            None => roots.push(outline),
+        }
+    }
+    if !stack.is_empty() {
+        return Err("unclosed <outline>".to_owned());
+    }
+    Ok(roots)
+}
+
+fn set_attribute(node: &mut Node, key: &str, value: String) -> Result<(), String> {
+    let time = |value: &str| {
+        value
+            .parse::<u64>()
+            .map_err(|_| format!("invalid {}: {}", key, value))
+    };
+    match key {
+        "text" => node.content = value,
+        "_note" => node.free_text = Some(value),
+        "_complete" => node.stricken = value == "true",
+        "void_collapsed" => node.collapsed = value == "true",
+        "void_ctime" => node.meta.ctime = time(&value)?,
+        "void_mtime" => node.meta.mtime = time(&value)?,
+        "void_finish_time" => node.meta.finish_time = Some(time(&value)?),
+        "void_due" => node.meta.due = Some(time(&value)?),
+        "void_tags" => {
+            for tag in value.lines() {
+                let mut parts = tag.splitn(2, '=');
+                let k = parts.next().unwrap().to_owned();
+                let v = parts.next().unwrap_or("").to_owned();
+                node.meta.tags.insert(k, v);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[test]
+fn test_opml_round_trip() {
+    let mut screen = Screen::default();
+    let project = screen.add_node(0, "plans & <ideas>".to_owned()).unwrap();
+    let first = screen
+        .add_node(project, "first \"one\"".to_owned())
+        .unwrap();
+    screen.add_node(0, "elsewhere".to_owned()).unwrap();
+    screen.finish_node(first);
+    {
+        let node = screen.nodes.get_mut(&project).unwrap();
+        node.free_text = Some("line one\nline two".to_owned());
+        node.collapsed = true;
+        node.meta.due = Some(42);
+        node.meta.tags.insert("prio".to_owned(), "3".to_owned());
+    }
+
+    let opml = to_opml(&screen, 0);
+    let outlines = from_opml(&opml).unwrap();
+    assert_eq!(outlines.len(), 2);
+    let imported = &outlines[0].node;
+    let original = &screen.nodes[&project];
+    assert_eq!(imported.content, original.content);
+    assert_eq!(imported.free_text, original.free_text);
+    assert_eq!(imported.collapsed, original.collapsed);
+    assert_eq!(imported.meta, original.meta);
+    let imported_first = &outlines[0].children[0].node;
+    assert!(imported_first.stricken);
+    assert_eq!(imported_first.meta, screen.nodes[&first].meta);
+    assert_eq!(outlines[1].node.content, "elsewhere");
+
+    // outlines written by other tools, with comments and odd quoting
+    let outlines = from_opml(
+        "<opml><body><!-- <outline text='no'/> -->
+        <outline text='a &amp; b' _complete='true'><outline text=\"c&#x21;\"/></outline>
+        </body></opml>",
+    )
+    .unwrap();
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(outlines[0].node.content, "a & b");
+    assert!(outlines[0].node.meta.finish_time.is_some());
+    assert_eq!(outlines[0].children[0].node.content, "c!");
+    assert!(from_opml("<outline text='a'>").is_err());
+}
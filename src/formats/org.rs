@@ -0,0 +1,398 @@
+use std::{env, fmt::Write};
+
+use regex::Regex;
+use time::{OffsetDateTime, Time, UtcOffset};
+
+use crate::{dateparse, formats::Outline, NodeID, Screen, TagDB};
+
+// which priority cookie a #prio= value becomes. each letter
+// stands for the priorities at or above its value, and the
+// mapping may be overridden with ORG_PRIORITIES, like `A=5,B=3,C=1`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrgPriorities(Vec<(char, usize)>);
+
+impl Default for OrgPriorities {
+    fn default() -> OrgPriorities { OrgPriorities(vec![('A', 3), ('B', 2), ('C', 1)]) }
+}
+
+impl OrgPriorities {
+    pub fn from_env() -> Result<OrgPriorities, String> {
+        match env::var("ORG_PRIORITIES") {
+            Ok(spec) => OrgPriorities::parse(&spec),
+            Err(_) => Ok(OrgPriorities::default()),
+        }
+    }
+
+    pub fn parse(spec: &str) -> Result<OrgPriorities, String> {
+        let mut mapping = vec![];
+        for pair in spec.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+            let invalid = || format!("invalid org priority: {}", pair);
+            let mut parts = pair.splitn(2, '=');
+            let mut letter = parts.next().unwrap().trim().chars();
+            let cookie = match (letter.next(), letter.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => c.to_ascii_uppercase(),
+                _ => return Err(invalid()),
+            };
+            let prio = parts
+                .next()
+                .and_then(|p| p.trim().parse().ok())
+                .ok_or_else(invalid)?;
+            mapping.push((cookie, prio));
+        }
+        if mapping.is_empty() {
+            return Err("no org priorities given".to_owned());
+        }
+        mapping.sort_by_key(|&(_, prio)| std::cmp::Reverse(prio));
+        Ok(OrgPriorities(mapping))
+    }
+
+    fn cookie(&self, prio: usize) -> Option<char> {
+        self.0.iter().find(|&&(_, p)| p <= prio).map(|&(c, _)| c)
+    }
+
+    fn prio(&self, cookie: char) -> Option<usize> {
+        self.0.iter().find(|&&(c, _)| c == cookie).map(|&(_, p)| p)
+    }
+}
+
+// an org document with a headline per node. like the markdown
+// export, exporting the root yields its children at the top level.
+pub fn to_org(screen: &Screen, node_id: NodeID, prios: &OrgPriorities) -> String {
+    let mut out = String::new();
+    let title = &screen.nodes[&node_id].content;
+    if !title.is_empty() {
+        writeln!(&mut out, "#+TITLE: {}", title).unwrap();
+    }
+    if node_id == 0 {
+        for &child in &screen.nodes[&0].children {
+            write_headline(screen, child, 1, prios, &mut out);
+        }
+    } else {
+        write_headline(screen, node_id, 1, prios, &mut out);
+    }
+    out
+}
+
+fn write_headline(
+    screen: &Screen,
+    node_id: NodeID,
+    level: usize,
+    prios: &OrgPriorities,
+    out: &mut String,
+) {
+    lazy_static! {
+        static ref RE_TAG: Regex = Regex::new(r"^#([\w@%]+)$").unwrap();
+        static ref RE_PRIO: Regex = Regex::new(r"^#prio=(\d+)$").unwrap();
+    }
+
+    let node = &screen.nodes[&node_id];
+    let in_task = super::is_task(screen, node_id);
+
+    // plain hashtags move to the end as org tags, and a #prio= with
+    // a cookie becomes the cookie. everything else stays in the title.
+    let mut title = vec![];
+    let mut tags = vec![];
+    let mut cookie = None;
+    for word in node.content.split_whitespace() {
+        if let Some(caps) = RE_TAG.captures(word) {
+            tags.push(caps.get(1).unwrap().as_str());
+            continue;
+        }
+        let prio_cookie = RE_PRIO
+            .captures(word)
+            .and_then(|caps| caps[1].parse().ok())
+            .and_then(|prio| prios.cookie(prio));
+        match prio_cookie {
+            Some(c) if cookie.is_none() => cookie = Some(c),
+            _ => title.push(word),
+        }
+    }
+
+    let mut parts = vec![];
+    if node.stricken {
+        parts.push("DONE".to_owned());
+    } else if in_task {
+        parts.push("TODO".to_owned());
+    }
+    if let Some(c) = cookie {
+        parts.push(format!("[#{}]", c));
+    }
+    if !title.is_empty() {
+        parts.push(title.join(" "));
+    }
+    if !tags.is_empty() {
+        parts.push(format!(":{}:", tags.join(":")));
+    }
+    writeln!(out, "{} {}", "*".repeat(level), parts.join(" ")).unwrap();
+
+    let mut planning = vec![];
+    if let Some(finish_time) = node.meta.finish_time {
+        planning.push(format!("CLOSED: [{}]", timestamp(finish_time, true)));
+    }
+    if let Some(due) = node.meta.due {
+        planning.push(format!("DEADLINE: <{}>", timestamp(due, false)));
+    }
+    if !planning.is_empty() {
+        writeln!(out, "{}", planning.join(" ")).unwrap();
+    }
+
+    if let Some(ref free_text) = node.free_text {
+        for line in free_text.lines() {
+            // keep body lines from being read back as headlines
+            if line.starts_with('*') {
+                out.push(' ');
+            }
+            writeln!(out, "{}", line).unwrap();
+        }
+    }
+
+    for &child in &node.children {
+        write_headline(screen, child, level + 1, prios, out);
+    }
+}
+
+// dates that fall on local midnight are written without a time
+fn timestamp(secs: u64, with_time: bool) -> String {
+    let utc = OffsetDateTime::from_unix_timestamp(secs as i64);
+    let local = utc.to_offset(UtcOffset::local_offset_at(utc));
+    if with_time || local.time() != Time::midnight() {
+        local.format("%Y-%m-%d %a %H:%M")
+    } else {
+        local.format("%Y-%m-%d %a")
+    }
+}
+
+// org timestamps carry the day of the week, like `2026-11-01 Sun 10:00`
+fn parse_timestamp(text: &str) -> Option<u64> {
+    lazy_static! {
+        static ref RE_TIMESTAMP: Regex =
+            Regex::new(r"^(\d{4}-\d{2}-\d{2})(?:\s+[^\d\s]+)?(?:\s+(\d{1,2}:\d{2}))?").unwrap();
+    }
+    let caps = RE_TIMESTAMP.captures(text.trim())?;
+    let date = match caps.get(2) {
+        Some(time) => format!("{} {}", &caps[1], time.as_str()),
+        None => caps[1].to_owned(),
+    };
+    dateparse(&date).ok()
+}
+
+// a headline being read, with the level it was found at and
+// whether it's inside a #task
+struct Headline {
+    level: usize,
+    in_task: bool,
+    outline: Outline,
+}
+
+// reads headlines and their bodies. text before the first headline,
+// property drawers and SCHEDULED timestamps are skipped.
+pub fn from_org(text: &str, prios: &OrgPriorities) -> Vec<Outline> {
+    lazy_static! {
+        static ref RE_HEADLINE: Regex = Regex::new(r"^(\*+)(?:\s+(.*))?$").unwrap();
+        static ref RE_KEYWORD: Regex = Regex::new(r"^(TODO|DONE)(?:\s+|$)").unwrap();
+        static ref RE_COOKIE: Regex = Regex::new(r"^\[#([A-Za-z0-9])\](?:\s+|$)").unwrap();
+        static ref RE_TAGS: Regex = Regex::new(r"(?:^|\s+):((?:[\w@#%]+:)+)$").unwrap();
+        static ref RE_PLANNING: Regex =
+            Regex::new(r"(CLOSED|DEADLINE|SCHEDULED):\s*[\[<]([^\]>]*)[\]>]").unwrap();
+        static ref RE_PLANNING_LINE: Regex =
+            Regex::new(r"^(?:(?:CLOSED|DEADLINE|SCHEDULED):\s*[\[<][^\]>]*[\]>]\s*)+$").unwrap();
+    }
+
+    let mut stack: Vec<Headline> = vec![];
+    let mut roots = vec![];
+    // planning lines and drawers may only follow a headline directly
+    let mut after_headline = false;
+    let mut in_drawer = false;
+    for line in text.lines() {
+        if let Some(caps) = RE_HEADLINE.captures(line) {
+            let level = caps[1].len();
+            while stack.last().is_some_and(|h| h.level >= level) {
+                pop(&mut stack, &mut roots);
+            }
+            let in_task = stack.last().is_some_and(|h| h.in_task);
+
+            let mut rest = caps.get(2).map_or("", |m| m.as_str()).trim();
+            let mut keyword = None;
+            if let Some(caps) = RE_KEYWORD.captures(rest) {
+                keyword = Some(caps.get(1).unwrap().as_str());
+                rest = &rest[caps.get(0).unwrap().end()..];
+            }
+            let mut prio = None;
+            if let Some(caps) = RE_COOKIE.captures(rest) {
+                let cookie = caps[1].chars().next().unwrap().to_ascii_uppercase();
+                prio = prios.prio(cookie);
+                rest = &rest[caps.get(0).unwrap().end()..];
+            }
+            let mut tags = vec![];
+            if let Some(caps) = RE_TAGS.captures(rest) {
+                tags = caps
+                    .get(1)
+                    .unwrap()
+                    .as_str()
+                    .split(':')
+                    .filter(|t| !t.is_empty())
+                    .collect();
+                rest = &rest[..caps.get(0).unwrap().start()];
+            }
+
+            let mut content = rest.to_owned();
+            let mut add = |word: String| {
+                if !content.is_empty() {
+                    content.push(' ');
+                }
+                content.push_str(&word);
+            };
+            // a TODO outside of any #task starts one
+            if keyword == Some("TODO") && !in_task && !tags.contains(&"task") {
+                tags.push("task");
+            }
+            for tag in tags {
+                add(format!("#{}", tag));
+            }
+            if let Some(prio) = prio {
+                add(format!("#prio={}", prio));
+            }
+
+            let mut outline = Outline::new(&content);
+            outline.node.stricken = keyword == Some("DONE");
+            stack.push(Headline {
+                level,
+                in_task: in_task || tags_task(&content),
+                outline,
+            });
+            after_headline = true;
+            in_drawer = false;
+            continue;
+        }
+
+        let headline = match stack.last_mut() {
+            Some(headline) => headline,
+            None => continue,
+        };
+        let node = &mut headline.outline.node;
+        let trimmed = line.trim();
+        if in_drawer {
+            in_drawer = trimmed != ":END:";
+            continue;
+        }
+        if after_headline && trimmed == ":PROPERTIES:" {
+            in_drawer = true;
+            continue;
+        }
+        if after_headline && RE_PLANNING_LINE.is_match(trimmed) {
+            for caps in RE_PLANNING.captures_iter(trimmed) {
+                let time = parse_timestamp(&caps[2]);
+                match &caps[1] {
+                    "CLOSED" => node.meta.finish_time = time,
+                    "DEADLINE" => node.meta.due = time,
+                    _ => {}
+                }
+            }
+            continue;
+        }
+        after_headline = false;
+
+        let line = line
+            .strip_prefix(' ')
+            .filter(|l| l.starts_with('*'))
+            .unwrap_or(line);
+        let free_text = node.free_text.get_or_insert_with(String::new);
+        free_text.push_str(line.trim_end());
+        free_text.push('\n');
+    }
+    while !stack.is_empty() {
+        pop(&mut stack, &mut roots);
+    }
+    roots
+}
+
+// whether a node's text tags it #task, read the way the tag index reads it
+fn tags_task(content: &str) -> bool {
+    let mut tag_db = TagDB::default();
+    tag_db.reindex(0, content.to_owned());
+    tag_db.node_tags(0).contains("task")
+}
+
+// finishes the innermost headline, attaching it to its parent
+fn pop(stack: &mut Vec<Headline>, roots: &mut Vec<Outline>) {
+    let mut outline = stack.pop().unwrap().outline;
+    let node = &mut outline.node;
+    if let Some(free_text) = node.free_text.take() {
+        let free_text = free_text.trim_matches('\n');
+        if !free_text.is_empty() {
+            node.free_text = Some(free_text.to_owned());
+        }
+    }
+    if node.stricken && node.meta.finish_time.is_none() {
+        node.meta.finish();
+    }
+    match stack.last_mut() {
+        Some(parent) => parent.outline.children.push(outline),
+        None => roots.push(outline),
+    }
+}
+
+#[test]
+fn test_org_round_trip() {
+    let prios = OrgPriorities::default();
+    let mut screen = Screen::default();
+    let project = screen
+        .add_node(0, "plans #task #work #prio=3".to_owned())
+        .unwrap();
+    let first = screen
+        .add_node(project, "first #prio=7 #size=2".to_owned())
+        .unwrap();
+    let second = screen.add_node(project, "second".to_owned()).unwrap();
+    screen.add_node(0, "elsewhere".to_owned()).unwrap();
+    screen.finish_node(first);
+    {
+        let node = screen.nodes.get_mut(&project).unwrap();
+        node.free_text = Some("line one\n* not a headline".to_owned());
+        node.meta.due = Some(parse_timestamp("2026-11-01").unwrap());
+    }
+
+    let org = to_org(&screen, 0, &prios);
+    assert!(org.contains("* TODO [#A] plans :task:work:\nDEADLINE: <2026-11-01 Sun>\n"));
+    assert!(org.contains("** DONE [#A] first #size=2\nCLOSED: ["));
+    assert!(org.contains("** TODO second\n"));
+    assert!(org.contains("* elsewhere\n"));
+
+    let outlines = from_org(&org, &prios);
+    assert_eq!(outlines.len(), 2);
+    let imported = &outlines[0].node;
+    let original = &screen.nodes[&project];
+    assert_eq!(imported.content, "plans #task #work #prio=3");
+    assert_eq!(imported.free_text, original.free_text);
+    assert_eq!(imported.meta.due, original.meta.due);
+    let imported_first = &outlines[0].children[0].node;
+    assert!(imported_first.stricken);
+    assert_eq!(imported_first.content, "first #size=2 #prio=3");
+    // CLOSED is written to the minute
+    let finish_time = screen.nodes[&first].meta.finish_time.unwrap();
+    assert_eq!(
+        imported_first.meta.finish_time,
+        Some(finish_time - finish_time % 60)
+    );
+    assert_eq!(
+        outlines[0].children[1].node.content,
+        screen.nodes[&second].content
+    );
+    assert_eq!(outlines[1].node.content, "elsewhere");
+
+    // documents written in emacs, with a custom mapping
+    let prios = OrgPriorities::parse("a=9, B=1").unwrap();
+    let outlines = from_org(
+        "#+TITLE: notes\nignored\n* TODO [#A] call bob  :phone:\n  :PROPERTIES:\n  :ID: x\n  :END:\n  soon\n*** DONE deep\n** [#B]",
+        &prios,
+    );
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(outlines[0].node.content, "call bob #phone #task #prio=9");
+    assert_eq!(outlines[0].node.free_text, Some("  soon".to_owned()));
+    assert!(outlines[0].children[0].node.meta.finish_time.is_some());
+    assert_eq!(outlines[0].children[1].node.content, "#prio=1");
+    // only a whole #task tag makes the headlines below it tasks already
+    let outlines = from_org("* chores :tasks:\n** TODO sweep", &prios);
+    assert_eq!(outlines[0].children[0].node.content, "sweep #task");
+    assert!(OrgPriorities::parse("A=x").is_err());
+    assert!(OrgPriorities::parse("").is_err());
+}
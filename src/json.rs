@@ -0,0 +1,396 @@
+use std::{fmt::Write, iter::Peekable, str::CharIndices};
+
+// how deeply arrays and objects may nest before parsing gives up,
+// well short of running out of stack
+const MAX_DEPTH: usize = 128;
+
+// just enough json to read and write our own data. numbers are
+// kept as text so that large ids and timestamps survive untouched,
+// and objects keep their keys in order so output is stable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Json::Number(ref n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Json::Number(ref n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Json::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Json::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match *self {
+            Json::Array(ref items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Json)]> {
+        match *self {
+            Json::Object(ref fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        match *self {
+            Json::Array(ref items) => items.is_empty(),
+            Json::Object(ref fields) => fields.is_empty(),
+            _ => true,
+        }
+    }
+
+    // two space indentation, with arrays and objects that only
+    // hold scalars kept on one line
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write(0, &mut out);
+        out.push('\n');
+        out
+    }
+
+    fn write(&self, depth: usize, out: &mut String) {
+        match *self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(b) => write!(out, "{}", b).unwrap(),
+            Json::Number(ref n) => out.push_str(n),
+            Json::String(ref s) => out.push_str(&json_string(s)),
+            Json::Array(ref items) => {
+                if items.iter().all(Json::is_scalar) {
+                    out.push('[');
+                    for (i, item) in items.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(", ");
+                        }
+                        item.write(depth, out);
+                    }
+                    out.push(']');
+                    return;
+                }
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(&"  ".repeat(depth + 1));
+                    item.write(depth + 1, out);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                write!(out, "{}]", "  ".repeat(depth)).unwrap();
+            }
+            Json::Object(ref fields) => {
+                if fields.iter().all(|(_, v)| v.is_scalar()) {
+                    out.push('{');
+                    for (i, (k, v)) in fields.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(", ");
+                        }
+                        write!(out, "{}: ", json_string(k)).unwrap();
+                        v.write(depth, out);
+                    }
+                    out.push('}');
+                    return;
+                }
+                out.push_str("{\n");
+                for (i, (k, v)) in fields.iter().enumerate() {
+                    write!(out, "{}{}: ", "  ".repeat(depth + 1), json_string(k)).unwrap();
+                    v.write(depth + 1, out);
+                    out.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+                }
+                write!(out, "{}}}", "  ".repeat(depth)).unwrap();
+            }
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser {
+            text,
+            chars: text.char_indices().peekable(),
+            depth: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.peek() {
+            None => Ok(value),
+            Some(&(pos, _)) => Err(parser.error(pos, "trailing characters")),
+        }
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut ret = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\t' => ret.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(&mut ret, "\\u{:04x}", c as u32).unwrap(),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    // arrays and objects we're inside of
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, pos: usize, msg: &str) -> String {
+        let before = &self.text[..pos];
+        let line = before.matches('\n').count() + 1;
+        let col = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        format!("invalid json at line {} column {}: {}", line, col, msg)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn next(&mut self) -> Result<(usize, char), String> {
+        self.chars
+            .next()
+            .ok_or_else(|| self.error(self.text.len(), "unexpected end"))
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.next()? {
+            (_, c) if c == expected => Ok(()),
+            (pos, c) => Err(self.error(pos, &format!("expected {:?}, found {:?}", expected, c))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        let (pos, c) = match self.chars.peek() {
+            Some(&next) => next,
+            None => return Err(self.error(self.text.len(), "unexpected end")),
+        };
+        match c {
+            'n' => self.literal(pos, "null", Json::Null),
+            't' => self.literal(pos, "true", Json::Bool(true)),
+            'f' => self.literal(pos, "false", Json::Bool(false)),
+            '"' => self.string().map(Json::String),
+            '[' => self.nested(pos, Parser::array),
+            '{' => self.nested(pos, Parser::object),
+            '-' | '0'..='9' => {
+                let mut end = pos;
+                while let Some(&(i, c)) = self.chars.peek() {
+                    if !(c.is_ascii_digit() || "+-.eE".contains(c)) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    self.chars.next();
+                }
+                let number = &self.text[pos..end];
+                if number.parse::<f64>().is_err() {
+                    return Err(self.error(pos, "invalid number"));
+                }
+                Ok(Json::Number(number.to_owned()))
+            }
+            _ => Err(self.error(pos, &format!("unexpected {:?}", c))),
+        }
+    }
+
+    fn nested(
+        &mut self,
+        pos: usize,
+        parse: fn(&mut Self) -> Result<Json, String>,
+    ) -> Result<Json, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(pos, "nested too deeply"));
+        }
+        self.depth += 1;
+        let ret = parse(self);
+        self.depth -= 1;
+        ret
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.chars.next();
+        let mut items = vec![];
+        self.skip_whitespace();
+        if self.chars.peek().is_some_and(|&(_, c)| c == ']') {
+            self.chars.next();
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.next()? {
+                (_, ',') => {}
+                (_, ']') => return Ok(Json::Array(items)),
+                (pos, _) => return Err(self.error(pos, "expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.chars.next();
+        let mut fields = vec![];
+        self.skip_whitespace();
+        if self.chars.peek().is_some_and(|&(_, c)| c == '}') {
+            self.chars.next();
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next()? {
+                (_, ',') => {}
+                (_, '}') => return Ok(Json::Object(fields)),
+                (pos, _) => return Err(self.error(pos, "expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn literal(&mut self, pos: usize, word: &str, value: Json) -> Result<Json, String> {
+        if !self.text[pos..].starts_with(word) {
+            return Err(self.error(pos, "unknown literal"));
+        }
+        for _ in 0..word.len() {
+            self.chars.next();
+        }
+        Ok(value)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        match self.next()? {
+            (_, '"') => {}
+            (pos, _) => return Err(self.error(pos, "expected a string")),
+        }
+        let mut ret = String::new();
+        loop {
+            match self.next()? {
+                (_, '"') => return Ok(ret),
+                (pos, '\\') => {
+                    let c = match self.next()?.1 {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            let mut code = self.hex4(pos)?;
+                            // surrogate pairs encode characters beyond the bmp
+                            if (0xd800..0xdc00).contains(&code) {
+                                self.expect('\\')?;
+                                self.expect('u')?;
+                                let low = self.hex4(pos)?;
+                                if !(0xdc00..=0xdfff).contains(&low) {
+                                    return Err(self.error(pos, "invalid surrogate pair"));
+                                }
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            std::char::from_u32(code)
+                                .ok_or_else(|| self.error(pos, "invalid unicode escape"))?
+                        }
+                        _ => return Err(self.error(pos, "invalid escape")),
+                    };
+                    ret.push(c);
+                }
+                (_, c) => ret.push(c),
+            }
+        }
+    }
+
+    fn hex4(&mut self, pos: usize) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self.next()?.1.to_digit(16);
+            code = code * 16 + digit.ok_or_else(|| self.error(pos, "invalid unicode escape"))?;
+        }
+        Ok(code)
+    }
+}
+
+#[test]
+fn test_json() {
+    let text = r#"{"a": [1, -2.5e3, true, null], "b": {"c": "x\"\n\u00e9\ud83d\ude00"}, "d": []}"#;
+    let json = Json::parse(text).unwrap();
+    assert_eq!(
+        json.get("a").unwrap().as_array().unwrap()[0].as_u64(),
+        Some(1)
+    );
+    assert_eq!(
+        json.get("b").unwrap().get("c").unwrap().as_str(),
+        Some("x\"\né😀")
+    );
+    let pretty = json.pretty();
+    assert_eq!(
+        pretty,
+        "{\n  \"a\": [1, -2.5e3, true, null],\n  \"b\": {\"c\": \"x\\\"\\né😀\"},\n  \"d\": []\n}\n"
+    );
+    assert_eq!(Json::parse(&pretty).unwrap(), json);
+    assert_eq!(
+        Json::parse("18446744073709551615").unwrap().as_u64(),
+        Some(u64::MAX)
+    );
+    assert!(Json::parse("[1,]").is_err());
+    assert!(Json::parse("{\"a\" 1}").is_err());
+    assert_eq!(
+        Json::parse("[1\n  x]").unwrap_err(),
+        "invalid json at line 2 column 3: expected ',' or ']'"
+    );
+    assert_eq!(
+        Json::parse(r#""\ud83d\u0041""#).unwrap_err(),
+        "invalid json at line 1 column 2: invalid surrogate pair"
+    );
+    assert!(Json::parse(r#""\ude00""#).is_err());
+    let deep = |n| format!("{}{}", "[".repeat(n), "]".repeat(n));
+    assert!(Json::parse(&deep(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        Json::parse(&deep(MAX_DEPTH + 1)).unwrap_err(),
+        format!(
+            "invalid json at line 1 column {}: nested too deeply",
+            MAX_DEPTH + 1
+        )
+    );
+    assert!(Json::parse(&deep(1_000_000)).is_err());
+}
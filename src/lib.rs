@@ -6,19 +6,26 @@ extern crate lazy_static;
 #[macro_use]
 extern crate log;
 
+mod cmd;
 mod colors;
 mod config;
 mod dateparse;
+mod formats;
+mod json;
 mod logging;
 mod meta;
 mod node;
 mod pack;
 mod pb;
 mod plot;
+mod query;
 mod screen;
 mod serialization;
 mod tagdb;
+mod tagexpr;
 mod task;
+mod trash;
+mod undo;
 
 use std::{
     cmp,
@@ -31,14 +38,19 @@ use regex::Regex;
 pub use crate::{
     colors::random_fg_color,
     config::{Action, Config},
-    dateparse::dateparse,
+    dateparse::{dateparse, DateError},
+    formats::{export, import, Outline, EXPORT_FORMATS, IMPORT_FORMATS},
+    json::json_string,
     logging::init_screen_log,
-    meta::Meta,
+    meta::{Measurement, Meta},
     node::Node,
     pack::Pack,
+    query::Query,
     screen::Screen,
-    serialization::{deserialize_screen, serialize_screen},
-    tagdb::TagDB,
+    serialization::{deserialize_screen, screen_from_json, screen_to_json, serialize_screen},
+    tagdb::{TagCount, TagDB},
+    trash::TrashEntry,
+    undo::{Op, UndoLog},
 };
 
 pub type Coords = (u16, u16);
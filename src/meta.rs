@@ -1,14 +1,25 @@
-use std::collections::HashMap;
+use std::{cmp::max, collections::HashMap};
 
 use crate::now;
 
-#[derive(Debug, Clone)]
+// a reading taken at some time, for nodes tagged `#metric=<name>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub time: u64,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Meta {
     pub ctime: u64,
     pub mtime: u64,
     pub finish_time: Option<u64>,
     pub due: Option<u64>,
     pub tags: HashMap<String, String>,
+    // oldest first
+    pub measurements: Vec<Measurement>,
+    // when a `#habit` node was checked off, oldest first
+    pub completions: Vec<u64>,
 }
 
 impl Default for Meta {
@@ -20,6 +31,8 @@ impl Default for Meta {
             finish_time: None,
             due: None,
             tags: HashMap::new(),
+            measurements: vec![],
+            completions: vec![],
         }
     }
 }
@@ -37,7 +50,146 @@ impl Meta {
         self.finish_time = None;
     }
 
+    pub fn record(&mut self, time: u64, value: f64) {
+        let idx = self.measurements.partition_point(|m| m.time <= time);
+        self.measurements.insert(idx, Measurement { time, value });
+    }
+
+    // the measurements taken in a time range, both ends included
+    pub fn measurements_between(&self, since: Option<u64>, until: Option<u64>) -> &[Measurement] {
+        let start = since.map_or(0, |t| self.measurements.partition_point(|m| m.time < t));
+        let end = until.map_or(self.measurements.len(), |t| {
+            self.measurements.partition_point(|m| m.time <= t)
+        });
+        &self.measurements[start..max(start, end)]
+    }
+
+    pub fn complete(&mut self, time: u64) {
+        let idx = self.completions.partition_point(|&t| t <= time);
+        self.completions.insert(idx, time);
+    }
+
+    // how many completions fall in each of the last `n` periods of
+    // `every` seconds before `end`, oldest first
+    pub fn completions_per_period(&self, every: u64, end: u64, n: usize) -> Vec<i64> {
+        let mut counts = vec![0; n];
+        for &t in &self.completions {
+            if let Some(ago) = periods_ago(t, every, end) {
+                if ago < n {
+                    counts[n - 1 - ago] += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    // the current and longest runs of periods of `every` seconds before
+    // `end` with a completion in each. the current run isn't broken
+    // until the last period has gone by without one.
+    pub fn streaks(&self, every: u64, end: u64) -> (usize, usize) {
+        let mut done: Vec<usize> = self
+            .completions
+            .iter()
+            .filter_map(|&t| periods_ago(t, every, end))
+            .collect();
+        done.sort();
+        done.dedup();
+        let mut run = 0;
+        let mut longest = 0;
+        for (i, &ago) in done.iter().enumerate() {
+            run = if i > 0 && done[i - 1] + 1 == ago {
+                run + 1
+            } else {
+                1
+            };
+            longest = max(longest, run);
+        }
+        let current = match done.first() {
+            Some(&first) if first <= 1 => done
+                .iter()
+                .zip(first..)
+                .take_while(|&(&ago, expected)| ago == expected)
+                .count(),
+            _ => 0,
+        };
+        (current, longest)
+    }
+
     pub fn at(&self) -> u64 {
         self.finish_time.unwrap_or(self.mtime)
     }
+
+    // a countdown to the due date, like `⏰3d`, or `⚠2d` once it's overdue
+    pub fn due_marker(&self, now: u64) -> Option<String> {
+        let span = |secs: u64| match secs {
+            s if s < 60 * 60 => format!("{}m", s / 60),
+            s if s < 60 * 60 * 24 => format!("{}h", s / (60 * 60)),
+            s => format!("{}d", s / (60 * 60 * 24)),
+        };
+        self.due.map(|due| {
+            if due >= now {
+                format!("⏰{}", span(due - now))
+            } else {
+                format!("⚠{}", span(now - due))
+            }
+        })
+    }
+}
+
+// which period of `every` seconds before `end` a time falls in,
+// counting back from zero for the last
+fn periods_ago(time: u64, every: u64, end: u64) -> Option<usize> {
+    if time >= end || every == 0 {
+        None
+    } else {
+        Some(((end - 1 - time) / every) as usize)
+    }
+}
+
+#[test]
+fn test_due_marker() {
+    let mut meta = Meta::default();
+    assert_eq!(meta.due_marker(0), None);
+    meta.due = Some(60 * 60 * 24 * 3);
+    assert_eq!(meta.due_marker(0), Some("⏰3d".to_owned()));
+    assert_eq!(
+        meta.due_marker(60 * 60 * 24 * 3 + 90),
+        Some("⚠1m".to_owned())
+    );
+}
+
+#[test]
+fn test_measurements() {
+    let mut meta = Meta::default();
+    meta.record(20, 2.0);
+    meta.record(10, 1.0);
+    meta.record(30, 3.0);
+    meta.record(20, 2.5);
+    let values = |since, until| -> Vec<f64> {
+        meta.measurements_between(since, until)
+            .iter()
+            .map(|m| m.value)
+            .collect()
+    };
+    assert_eq!(values(None, None), vec![1.0, 2.0, 2.5, 3.0]);
+    assert_eq!(values(Some(20), Some(20)), vec![2.0, 2.5]);
+    assert_eq!(values(Some(11), None), vec![2.0, 2.5, 3.0]);
+    assert_eq!(values(None, Some(19)), vec![1.0]);
+    assert_eq!(values(Some(30), Some(10)), Vec::<f64>::new());
+}
+
+#[test]
+fn test_streaks() {
+    let mut meta = Meta::default();
+    assert_eq!(meta.streaks(10, 100), (0, 0));
+    // periods end at 100, 90, 80, ..., with none in 60..80
+    for &t in &[95, 85, 81, 55, 45, 35, 25, 100] {
+        meta.complete(t);
+    }
+    assert_eq!(meta.completions_per_period(10, 100, 5), vec![1, 0, 0, 2, 1]);
+    assert_eq!(meta.streaks(10, 100), (2, 4));
+    assert_eq!(meta.streaks(10, 110), (3, 4));
+    // with nothing yet this period, the run so far still counts
+    assert_eq!(meta.streaks(10, 120), (3, 4));
+    assert_eq!(meta.streaks(10, 130), (0, 4));
 }
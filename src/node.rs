@@ -1,6 +1,17 @@
-use crate::{random_fg_color, Coords, Meta, NodeID};
+use regex::Regex;
 
-#[derive(Debug, Clone)]
+use crate::{
+    dateparse::{dateparse_due, duration, DateError},
+    now, random_fg_color, Coords, Meta, NodeID,
+};
+
+const DAY: u64 = 60 * 60 * 24;
+
+lazy_static! {
+    static ref RE_DUE: Regex = Regex::new(r"(^|\s)#due=(\S*)").unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Node {
     pub rooted_coords: Coords,
     pub parent_id: NodeID,
@@ -40,7 +51,13 @@ impl Default for Node {
 impl Node {
     pub fn toggle_collapsed(&mut self) { self.collapsed = !self.collapsed; }
 
+    // a habit is never done for good: striking it checks it off for
+    // this period and leaves it open for the next
     pub fn toggle_stricken(&mut self) {
+        if !self.stricken && self.habit_period().is_some() {
+            self.meta.complete(now().as_secs());
+            return;
+        }
         if self.stricken {
             self.meta.unfinish();
         } else {
@@ -49,5 +66,48 @@ impl Node {
         self.stricken = !self.stricken;
     }
 
+    // how often a `#habit` node recurs, from `#every=3w` or daily by
+    // default, or an error if the period can't be read
+    pub fn habit_period(&self) -> Option<Result<u64, String>> {
+        lazy_static! {
+            static ref RE_HABIT: Regex = Regex::new(r"(^|\s)#habit(\s|$)").unwrap();
+            static ref RE_EVERY: Regex = Regex::new(r"(^|\s)#every=(\S*)").unwrap();
+        }
+        if !RE_HABIT.is_match(&self.content) {
+            return None;
+        }
+        Some(match RE_EVERY.captures(&self.content) {
+            None => Ok(DAY),
+            Some(caps) => duration(&caps[2].to_lowercase())
+                .filter(|&secs| secs > 0)
+                .ok_or_else(|| format!("can't repeat every {:?}", &caps[2])),
+        })
+    }
+
     pub fn toggle_hide_stricken(&mut self) { self.hide_stricken = !self.hide_stricken; }
+
+    // `#due=3d` sets the due date and is taken out of the text, and
+    // `#due=none` clears it. dates that can't be parsed are left alone.
+    pub fn take_due_tag(&mut self) -> Result<(), DateError> {
+        let spec = match RE_DUE.captures(&self.content) {
+            Some(caps) => caps[2].to_owned(),
+            None => return Ok(()),
+        };
+        self.meta.due = if spec.is_empty() || spec == "none" {
+            None
+        } else {
+            Some(dateparse_due(&spec)?)
+        };
+        self.content = RE_DUE.replace(&self.content, "").trim().to_owned();
+        Ok(())
+    }
+
+    // why a `#due=` left in the text couldn't be taken out of it
+    pub fn due_tag_error(&self) -> Option<DateError> {
+        let caps = RE_DUE.captures(&self.content)?;
+        match &caps[2] {
+            "" | "none" => None,
+            spec => dateparse_due(spec).err(),
+        }
+    }
 }
@@ -487,6 +487,226 @@ impl ::protobuf::reflect::ProtobufValue for Gps {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct Measurement {
+    // message fields
+    time: ::std::option::Option<u64>,
+    value: ::std::option::Option<f64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Measurement {}
+
+impl Measurement {
+    pub fn new() -> Measurement {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Measurement {
+        static mut instance: ::protobuf::lazy::Lazy<Measurement> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Measurement,
+        };
+        unsafe {
+            instance.get(Measurement::new)
+        }
+    }
+
+    // required uint64 time = 1;
+
+    pub fn clear_time(&mut self) {
+        self.time = ::std::option::Option::None;
+    }
+
+    pub fn has_time(&self) -> bool {
+        self.time.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: u64) {
+        self.time = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_time(&self) -> u64 {
+        self.time.unwrap_or(0)
+    }
+
+    fn get_time_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.time
+    }
+
+    fn mut_time_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.time
+    }
+
+    // required double value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: f64) {
+        self.value = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value.unwrap_or(0.)
+    }
+
+    fn get_value_for_reflect(&self) -> &::std::option::Option<f64> {
+        &self.value
+    }
+
+    fn mut_value_for_reflect(&mut self) -> &mut ::std::option::Option<f64> {
+        &mut self.value
+    }
+}
+
+impl ::protobuf::Message for Measurement {
+    fn is_initialized(&self) -> bool {
+        if self.time.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.time = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_double()?;
+                    self.value = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.time {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.value {
+            my_size += 9;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.time {
+            os.write_uint64(1, v)?;
+        };
+        if let Some(v) = self.value {
+            os.write_double(2, v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Measurement {
+    fn new() -> Measurement {
+        Measurement::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Measurement>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "time",
+                    Measurement::get_time_for_reflect,
+                    Measurement::mut_time_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "value",
+                    Measurement::get_value_for_reflect,
+                    Measurement::mut_value_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Measurement>(
+                    "Measurement",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Measurement {
+    fn clear(&mut self) {
+        self.clear_time();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Measurement {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Measurement {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Meta {
     // message fields
@@ -496,6 +716,8 @@ pub struct Meta {
     gps: ::protobuf::SingularPtrField<Gps>,
     tags: ::protobuf::RepeatedField<Tag>,
     due: ::std::option::Option<u64>,
+    measurements: ::protobuf::RepeatedField<Measurement>,
+    completions: ::std::vec::Vec<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -700,6 +922,72 @@ impl Meta {
     fn mut_due_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.due
     }
+
+    // repeated .void.Measurement measurements = 7;
+
+    pub fn clear_measurements(&mut self) {
+        self.measurements.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_measurements(&mut self, v: ::protobuf::RepeatedField<Measurement>) {
+        self.measurements = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_measurements(&mut self) -> &mut ::protobuf::RepeatedField<Measurement> {
+        &mut self.measurements
+    }
+
+    // Take field
+    pub fn take_measurements(&mut self) -> ::protobuf::RepeatedField<Measurement> {
+        ::std::mem::replace(&mut self.measurements, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_measurements(&self) -> &[Measurement] {
+        &self.measurements
+    }
+
+    fn get_measurements_for_reflect(&self) -> &::protobuf::RepeatedField<Measurement> {
+        &self.measurements
+    }
+
+    fn mut_measurements_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Measurement> {
+        &mut self.measurements
+    }
+
+    // repeated uint64 completions = 8;
+
+    pub fn clear_completions(&mut self) {
+        self.completions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_completions(&mut self, v: ::std::vec::Vec<u64>) {
+        self.completions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_completions(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.completions
+    }
+
+    // Take field
+    pub fn take_completions(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.completions, ::std::vec::Vec::new())
+    }
+
+    pub fn get_completions(&self) -> &[u64] {
+        &self.completions
+    }
+
+    fn get_completions_for_reflect(&self) -> &::std::vec::Vec<u64> {
+        &self.completions
+    }
+
+    fn mut_completions_for_reflect(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.completions
+    }
 }
 
 impl ::protobuf::Message for Meta {
@@ -754,6 +1042,12 @@ impl ::protobuf::Message for Meta {
                     let tmp = is.read_uint64()?;
                     self.due = ::std::option::Option::Some(tmp);
                 },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.measurements)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.completions)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -786,6 +1080,13 @@ impl ::protobuf::Message for Meta {
         if let Some(v) = self.due {
             my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in &self.measurements {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.completions {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -814,6 +1115,14 @@ impl ::protobuf::Message for Meta {
         if let Some(v) = self.due {
             os.write_uint64(6, v)?;
         };
+        for v in &self.measurements {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.completions {
+            os.write_uint64(8, *v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -882,6 +1191,16 @@ impl ::protobuf::MessageStatic for Meta {
                     Meta::get_due_for_reflect,
                     Meta::mut_due_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Measurement>>(
+                    "measurements",
+                    Meta::get_measurements_for_reflect,
+                    Meta::mut_measurements_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "completions",
+                    Meta::get_completions_for_reflect,
+                    Meta::mut_completions_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Meta>(
                     "Meta",
                     fields,
@@ -900,6 +1219,8 @@ impl ::protobuf::Clear for Meta {
         self.clear_gps();
         self.clear_tags();
         self.clear_due();
+        self.clear_measurements();
+        self.clear_completions();
         self.unknown_fields.clear();
     }
 }
@@ -1942,10 +2263,12 @@ impl ::protobuf::reflect::ProtobufValue for Arrow {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Screen {
+pub struct TrashEntry {
     // message fields
+    parent_id: ::std::option::Option<u64>,
+    position: ::std::option::Option<u64>,
+    deleted_at: ::std::option::Option<u64>,
     nodes: ::protobuf::RepeatedField<Node>,
-    max_id: ::std::option::Option<u64>,
     arrows: ::protobuf::RepeatedField<Arrow>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
@@ -1953,57 +2276,1633 @@ pub struct Screen {
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Screen {}
+unsafe impl ::std::marker::Sync for TrashEntry {}
 
-impl Screen {
-    pub fn new() -> Screen {
+impl TrashEntry {
+    pub fn new() -> TrashEntry {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Screen {
-        static mut instance: ::protobuf::lazy::Lazy<Screen> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static TrashEntry {
+        static mut instance: ::protobuf::lazy::Lazy<TrashEntry> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Screen,
+            ptr: 0 as *const TrashEntry,
         };
         unsafe {
-            instance.get(Screen::new)
+            instance.get(TrashEntry::new)
         }
     }
 
-    // repeated .void.Node nodes = 1;
+    // required uint64 parent_id = 1;
 
-    pub fn clear_nodes(&mut self) {
-        self.nodes.clear();
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id = ::std::option::Option::None;
     }
 
-    // Param is passed by value, moved
-    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
-        self.nodes = v;
+    pub fn has_parent_id(&self) -> bool {
+        self.parent_id.is_some()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
-        &mut self.nodes
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: u64) {
+        self.parent_id = ::std::option::Option::Some(v);
     }
 
-    // Take field
-    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
-        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    pub fn get_parent_id(&self) -> u64 {
+        self.parent_id.unwrap_or(0)
     }
 
-    pub fn get_nodes(&self) -> &[Node] {
-        &self.nodes
+    fn get_parent_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.parent_id
     }
 
-    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
-        &self.nodes
+    fn mut_parent_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.parent_id
     }
 
-    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
-        &mut self.nodes
+    // required uint64 position = 2;
+
+    pub fn clear_position(&mut self) {
+        self.position = ::std::option::Option::None;
     }
 
-    // required uint64 max_id = 2;
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: u64) {
+        self.position = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_position(&self) -> u64 {
+        self.position.unwrap_or(0)
+    }
+
+    fn get_position_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.position
+    }
+
+    fn mut_position_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.position
+    }
+
+    // required uint64 deleted_at = 3;
+
+    pub fn clear_deleted_at(&mut self) {
+        self.deleted_at = ::std::option::Option::None;
+    }
+
+    pub fn has_deleted_at(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_at(&mut self, v: u64) {
+        self.deleted_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_deleted_at(&self) -> u64 {
+        self.deleted_at.unwrap_or(0)
+    }
+
+    fn get_deleted_at_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.deleted_at
+    }
+
+    fn mut_deleted_at_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.deleted_at
+    }
+
+    // repeated .void.Node nodes = 4;
+
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.nodes
+    }
+
+    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // repeated .void.Arrow arrows = 5;
+
+    pub fn clear_arrows(&mut self) {
+        self.arrows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_arrows(&mut self, v: ::protobuf::RepeatedField<Arrow>) {
+        self.arrows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_arrows(&mut self) -> &mut ::protobuf::RepeatedField<Arrow> {
+        &mut self.arrows
+    }
+
+    // Take field
+    pub fn take_arrows(&mut self) -> ::protobuf::RepeatedField<Arrow> {
+        ::std::mem::replace(&mut self.arrows, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_arrows(&self) -> &[Arrow] {
+        &self.arrows
+    }
+
+    fn get_arrows_for_reflect(&self) -> &::protobuf::RepeatedField<Arrow> {
+        &self.arrows
+    }
+
+    fn mut_arrows_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Arrow> {
+        &mut self.arrows
+    }
+}
+
+impl ::protobuf::Message for TrashEntry {
+    fn is_initialized(&self) -> bool {
+        if self.parent_id.is_none() {
+            return false;
+        };
+        if self.position.is_none() {
+            return false;
+        };
+        if self.deleted_at.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.parent_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.position = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.deleted_at = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.arrows)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.parent_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.position {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.deleted_at {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.arrows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.parent_id {
+            os.write_uint64(1, v)?;
+        };
+        if let Some(v) = self.position {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.deleted_at {
+            os.write_uint64(3, v)?;
+        };
+        for v in &self.nodes {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.arrows {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for TrashEntry {
+    fn new() -> TrashEntry {
+        TrashEntry::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<TrashEntry>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "parent_id",
+                    TrashEntry::get_parent_id_for_reflect,
+                    TrashEntry::mut_parent_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "position",
+                    TrashEntry::get_position_for_reflect,
+                    TrashEntry::mut_position_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "deleted_at",
+                    TrashEntry::get_deleted_at_for_reflect,
+                    TrashEntry::mut_deleted_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "nodes",
+                    TrashEntry::get_nodes_for_reflect,
+                    TrashEntry::mut_nodes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Arrow>>(
+                    "arrows",
+                    TrashEntry::get_arrows_for_reflect,
+                    TrashEntry::mut_arrows_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TrashEntry>(
+                    "TrashEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for TrashEntry {
+    fn clear(&mut self) {
+        self.clear_parent_id();
+        self.clear_position();
+        self.clear_deleted_at();
+        self.clear_nodes();
+        self.clear_arrows();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TrashEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TrashEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Op {
+    // message fields
+    edit: ::protobuf::SingularPtrField<EditOp>,
+    insert: ::protobuf::SingularPtrField<SubtreeOp>,
+    remove: ::protobuf::SingularPtrField<SubtreeOp>,
+    add_arrow: ::protobuf::SingularPtrField<Arrow>,
+    remove_arrow: ::protobuf::SingularPtrField<Arrow>,
+    group: ::protobuf::SingularPtrField<OpGroup>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Op {}
+
+impl Op {
+    pub fn new() -> Op {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Op {
+        static mut instance: ::protobuf::lazy::Lazy<Op> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Op,
+        };
+        unsafe {
+            instance.get(Op::new)
+        }
+    }
+
+    // optional .void.EditOp edit = 1;
+
+    pub fn clear_edit(&mut self) {
+        self.edit.clear();
+    }
+
+    pub fn has_edit(&self) -> bool {
+        self.edit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edit(&mut self, v: EditOp) {
+        self.edit = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edit(&mut self) -> &mut EditOp {
+        if self.edit.is_none() {
+            self.edit.set_default();
+        };
+        self.edit.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_edit(&mut self) -> EditOp {
+        self.edit.take().unwrap_or_else(|| EditOp::new())
+    }
+
+    pub fn get_edit(&self) -> &EditOp {
+        self.edit.as_ref().unwrap_or_else(|| EditOp::default_instance())
+    }
+
+    fn get_edit_for_reflect(&self) -> &::protobuf::SingularPtrField<EditOp> {
+        &self.edit
+    }
+
+    fn mut_edit_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<EditOp> {
+        &mut self.edit
+    }
+
+    // optional .void.SubtreeOp insert = 2;
+
+    pub fn clear_insert(&mut self) {
+        self.insert.clear();
+    }
+
+    pub fn has_insert(&self) -> bool {
+        self.insert.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insert(&mut self, v: SubtreeOp) {
+        self.insert = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_insert(&mut self) -> &mut SubtreeOp {
+        if self.insert.is_none() {
+            self.insert.set_default();
+        };
+        self.insert.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_insert(&mut self) -> SubtreeOp {
+        self.insert.take().unwrap_or_else(|| SubtreeOp::new())
+    }
+
+    pub fn get_insert(&self) -> &SubtreeOp {
+        self.insert.as_ref().unwrap_or_else(|| SubtreeOp::default_instance())
+    }
+
+    fn get_insert_for_reflect(&self) -> &::protobuf::SingularPtrField<SubtreeOp> {
+        &self.insert
+    }
+
+    fn mut_insert_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SubtreeOp> {
+        &mut self.insert
+    }
+
+    // optional .void.SubtreeOp remove = 3;
+
+    pub fn clear_remove(&mut self) {
+        self.remove.clear();
+    }
+
+    pub fn has_remove(&self) -> bool {
+        self.remove.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove(&mut self, v: SubtreeOp) {
+        self.remove = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remove(&mut self) -> &mut SubtreeOp {
+        if self.remove.is_none() {
+            self.remove.set_default();
+        };
+        self.remove.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remove(&mut self) -> SubtreeOp {
+        self.remove.take().unwrap_or_else(|| SubtreeOp::new())
+    }
+
+    pub fn get_remove(&self) -> &SubtreeOp {
+        self.remove.as_ref().unwrap_or_else(|| SubtreeOp::default_instance())
+    }
+
+    fn get_remove_for_reflect(&self) -> &::protobuf::SingularPtrField<SubtreeOp> {
+        &self.remove
+    }
+
+    fn mut_remove_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<SubtreeOp> {
+        &mut self.remove
+    }
+
+    // optional .void.Arrow add_arrow = 4;
+
+    pub fn clear_add_arrow(&mut self) {
+        self.add_arrow.clear();
+    }
+
+    pub fn has_add_arrow(&self) -> bool {
+        self.add_arrow.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_add_arrow(&mut self, v: Arrow) {
+        self.add_arrow = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_add_arrow(&mut self) -> &mut Arrow {
+        if self.add_arrow.is_none() {
+            self.add_arrow.set_default();
+        };
+        self.add_arrow.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_add_arrow(&mut self) -> Arrow {
+        self.add_arrow.take().unwrap_or_else(|| Arrow::new())
+    }
+
+    pub fn get_add_arrow(&self) -> &Arrow {
+        self.add_arrow.as_ref().unwrap_or_else(|| Arrow::default_instance())
+    }
+
+    fn get_add_arrow_for_reflect(&self) -> &::protobuf::SingularPtrField<Arrow> {
+        &self.add_arrow
+    }
+
+    fn mut_add_arrow_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Arrow> {
+        &mut self.add_arrow
+    }
+
+    // optional .void.Arrow remove_arrow = 5;
+
+    pub fn clear_remove_arrow(&mut self) {
+        self.remove_arrow.clear();
+    }
+
+    pub fn has_remove_arrow(&self) -> bool {
+        self.remove_arrow.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove_arrow(&mut self, v: Arrow) {
+        self.remove_arrow = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remove_arrow(&mut self) -> &mut Arrow {
+        if self.remove_arrow.is_none() {
+            self.remove_arrow.set_default();
+        };
+        self.remove_arrow.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remove_arrow(&mut self) -> Arrow {
+        self.remove_arrow.take().unwrap_or_else(|| Arrow::new())
+    }
+
+    pub fn get_remove_arrow(&self) -> &Arrow {
+        self.remove_arrow.as_ref().unwrap_or_else(|| Arrow::default_instance())
+    }
+
+    fn get_remove_arrow_for_reflect(&self) -> &::protobuf::SingularPtrField<Arrow> {
+        &self.remove_arrow
+    }
+
+    fn mut_remove_arrow_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<Arrow> {
+        &mut self.remove_arrow
+    }
+
+    // optional .void.OpGroup group = 6;
+
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    pub fn has_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: OpGroup) {
+        self.group = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group(&mut self) -> &mut OpGroup {
+        if self.group.is_none() {
+            self.group.set_default();
+        };
+        self.group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> OpGroup {
+        self.group.take().unwrap_or_else(|| OpGroup::new())
+    }
+
+    pub fn get_group(&self) -> &OpGroup {
+        self.group.as_ref().unwrap_or_else(|| OpGroup::default_instance())
+    }
+
+    fn get_group_for_reflect(&self) -> &::protobuf::SingularPtrField<OpGroup> {
+        &self.group
+    }
+
+    fn mut_group_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<OpGroup> {
+        &mut self.group
+    }
+}
+
+impl ::protobuf::Message for Op {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.edit)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.insert)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.remove)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.add_arrow)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.remove_arrow)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.group)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.edit.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.insert.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.remove.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.add_arrow.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.remove_arrow.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.group.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.edit.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.insert.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.remove.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.add_arrow.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.remove_arrow.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.group.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Op {
+    fn new() -> Op {
+        Op::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Op>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EditOp>>(
+                    "edit",
+                    Op::get_edit_for_reflect,
+                    Op::mut_edit_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SubtreeOp>>(
+                    "insert",
+                    Op::get_insert_for_reflect,
+                    Op::mut_insert_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SubtreeOp>>(
+                    "remove",
+                    Op::get_remove_for_reflect,
+                    Op::mut_remove_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Arrow>>(
+                    "add_arrow",
+                    Op::get_add_arrow_for_reflect,
+                    Op::mut_add_arrow_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Arrow>>(
+                    "remove_arrow",
+                    Op::get_remove_arrow_for_reflect,
+                    Op::mut_remove_arrow_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OpGroup>>(
+                    "group",
+                    Op::get_group_for_reflect,
+                    Op::mut_group_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Op>(
+                    "Op",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Op {
+    fn clear(&mut self) {
+        self.clear_edit();
+        self.clear_insert();
+        self.clear_remove();
+        self.clear_add_arrow();
+        self.clear_remove_arrow();
+        self.clear_group();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Op {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Op {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EditOp {
+    // message fields
+    before: ::protobuf::RepeatedField<Node>,
+    after: ::protobuf::RepeatedField<Node>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for EditOp {}
+
+impl EditOp {
+    pub fn new() -> EditOp {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static EditOp {
+        static mut instance: ::protobuf::lazy::Lazy<EditOp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EditOp,
+        };
+        unsafe {
+            instance.get(EditOp::new)
+        }
+    }
+
+    // repeated .void.Node before = 1;
+
+    pub fn clear_before(&mut self) {
+        self.before.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_before(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.before = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_before(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.before
+    }
+
+    // Take field
+    pub fn take_before(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.before, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_before(&self) -> &[Node] {
+        &self.before
+    }
+
+    fn get_before_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.before
+    }
+
+    fn mut_before_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.before
+    }
+
+    // repeated .void.Node after = 2;
+
+    pub fn clear_after(&mut self) {
+        self.after.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_after(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.after = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_after(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.after
+    }
+
+    // Take field
+    pub fn take_after(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.after, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_after(&self) -> &[Node] {
+        &self.after
+    }
+
+    fn get_after_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.after
+    }
+
+    fn mut_after_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.after
+    }
+}
+
+impl ::protobuf::Message for EditOp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.before)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.after)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.before {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.after {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.before {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.after {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for EditOp {
+    fn new() -> EditOp {
+        EditOp::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<EditOp>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "before",
+                    EditOp::get_before_for_reflect,
+                    EditOp::mut_before_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "after",
+                    EditOp::get_after_for_reflect,
+                    EditOp::mut_after_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<EditOp>(
+                    "EditOp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for EditOp {
+    fn clear(&mut self) {
+        self.clear_before();
+        self.clear_after();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EditOp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EditOp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SubtreeOp {
+    // message fields
+    parent_id: ::std::option::Option<u64>,
+    position: ::std::option::Option<u64>,
+    nodes: ::protobuf::RepeatedField<Node>,
+    arrows: ::protobuf::RepeatedField<Arrow>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SubtreeOp {}
+
+impl SubtreeOp {
+    pub fn new() -> SubtreeOp {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SubtreeOp {
+        static mut instance: ::protobuf::lazy::Lazy<SubtreeOp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SubtreeOp,
+        };
+        unsafe {
+            instance.get(SubtreeOp::new)
+        }
+    }
+
+    // required uint64 parent_id = 1;
+
+    pub fn clear_parent_id(&mut self) {
+        self.parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        self.parent_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: u64) {
+        self.parent_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_parent_id(&self) -> u64 {
+        self.parent_id.unwrap_or(0)
+    }
+
+    fn get_parent_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.parent_id
+    }
+
+    fn mut_parent_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.parent_id
+    }
+
+    // required uint64 position = 2;
+
+    pub fn clear_position(&mut self) {
+        self.position = ::std::option::Option::None;
+    }
+
+    pub fn has_position(&self) -> bool {
+        self.position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: u64) {
+        self.position = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_position(&self) -> u64 {
+        self.position.unwrap_or(0)
+    }
+
+    fn get_position_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.position
+    }
+
+    fn mut_position_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.position
+    }
+
+    // repeated .void.Node nodes = 3;
+
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.nodes
+    }
+
+    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // repeated .void.Arrow arrows = 4;
+
+    pub fn clear_arrows(&mut self) {
+        self.arrows.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_arrows(&mut self, v: ::protobuf::RepeatedField<Arrow>) {
+        self.arrows = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_arrows(&mut self) -> &mut ::protobuf::RepeatedField<Arrow> {
+        &mut self.arrows
+    }
+
+    // Take field
+    pub fn take_arrows(&mut self) -> ::protobuf::RepeatedField<Arrow> {
+        ::std::mem::replace(&mut self.arrows, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_arrows(&self) -> &[Arrow] {
+        &self.arrows
+    }
+
+    fn get_arrows_for_reflect(&self) -> &::protobuf::RepeatedField<Arrow> {
+        &self.arrows
+    }
+
+    fn mut_arrows_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Arrow> {
+        &mut self.arrows
+    }
+}
+
+impl ::protobuf::Message for SubtreeOp {
+    fn is_initialized(&self) -> bool {
+        if self.parent_id.is_none() {
+            return false;
+        };
+        if self.position.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.parent_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.position = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.arrows)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.parent_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.position {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.arrows {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.parent_id {
+            os.write_uint64(1, v)?;
+        };
+        if let Some(v) = self.position {
+            os.write_uint64(2, v)?;
+        };
+        for v in &self.nodes {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.arrows {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SubtreeOp {
+    fn new() -> SubtreeOp {
+        SubtreeOp::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SubtreeOp>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "parent_id",
+                    SubtreeOp::get_parent_id_for_reflect,
+                    SubtreeOp::mut_parent_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "position",
+                    SubtreeOp::get_position_for_reflect,
+                    SubtreeOp::mut_position_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                    "nodes",
+                    SubtreeOp::get_nodes_for_reflect,
+                    SubtreeOp::mut_nodes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Arrow>>(
+                    "arrows",
+                    SubtreeOp::get_arrows_for_reflect,
+                    SubtreeOp::mut_arrows_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SubtreeOp>(
+                    "SubtreeOp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SubtreeOp {
+    fn clear(&mut self) {
+        self.clear_parent_id();
+        self.clear_position();
+        self.clear_nodes();
+        self.clear_arrows();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SubtreeOp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SubtreeOp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OpGroup {
+    // message fields
+    ops: ::protobuf::RepeatedField<Op>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OpGroup {}
+
+impl OpGroup {
+    pub fn new() -> OpGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OpGroup {
+        static mut instance: ::protobuf::lazy::Lazy<OpGroup> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OpGroup,
+        };
+        unsafe {
+            instance.get(OpGroup::new)
+        }
+    }
+
+    // repeated .void.Op ops = 1;
+
+    pub fn clear_ops(&mut self) {
+        self.ops.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ops(&mut self, v: ::protobuf::RepeatedField<Op>) {
+        self.ops = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ops(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.ops
+    }
+
+    // Take field
+    pub fn take_ops(&mut self) -> ::protobuf::RepeatedField<Op> {
+        ::std::mem::replace(&mut self.ops, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    fn get_ops_for_reflect(&self) -> &::protobuf::RepeatedField<Op> {
+        &self.ops
+    }
+
+    fn mut_ops_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.ops
+    }
+}
+
+impl ::protobuf::Message for OpGroup {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ops)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ops {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ops {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OpGroup {
+    fn new() -> OpGroup {
+        OpGroup::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OpGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Op>>(
+                    "ops",
+                    OpGroup::get_ops_for_reflect,
+                    OpGroup::mut_ops_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OpGroup>(
+                    "OpGroup",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OpGroup {
+    fn clear(&mut self) {
+        self.clear_ops();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OpGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OpGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Screen {
+    // message fields
+    nodes: ::protobuf::RepeatedField<Node>,
+    max_id: ::std::option::Option<u64>,
+    arrows: ::protobuf::RepeatedField<Arrow>,
+    trash: ::protobuf::RepeatedField<TrashEntry>,
+    trash_days: ::std::option::Option<u64>,
+    undo: ::protobuf::RepeatedField<Op>,
+    redo: ::protobuf::RepeatedField<Op>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Screen {}
+
+impl Screen {
+    pub fn new() -> Screen {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Screen {
+        static mut instance: ::protobuf::lazy::Lazy<Screen> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Screen,
+        };
+        unsafe {
+            instance.get(Screen::new)
+        }
+    }
+
+    // repeated .void.Node nodes = 1;
+
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    fn get_nodes_for_reflect(&self) -> &::protobuf::RepeatedField<Node> {
+        &self.nodes
+    }
+
+    fn mut_nodes_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.nodes
+    }
+
+    // required uint64 max_id = 2;
 
     pub fn clear_max_id(&mut self) {
         self.max_id = ::std::option::Option::None;
@@ -2062,6 +3961,132 @@ impl Screen {
     fn mut_arrows_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Arrow> {
         &mut self.arrows
     }
+
+    // repeated .void.TrashEntry trash = 4;
+
+    pub fn clear_trash(&mut self) {
+        self.trash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trash(&mut self, v: ::protobuf::RepeatedField<TrashEntry>) {
+        self.trash = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_trash(&mut self) -> &mut ::protobuf::RepeatedField<TrashEntry> {
+        &mut self.trash
+    }
+
+    // Take field
+    pub fn take_trash(&mut self) -> ::protobuf::RepeatedField<TrashEntry> {
+        ::std::mem::replace(&mut self.trash, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_trash(&self) -> &[TrashEntry] {
+        &self.trash
+    }
+
+    fn get_trash_for_reflect(&self) -> &::protobuf::RepeatedField<TrashEntry> {
+        &self.trash
+    }
+
+    fn mut_trash_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<TrashEntry> {
+        &mut self.trash
+    }
+
+    // optional uint64 trash_days = 5;
+
+    pub fn clear_trash_days(&mut self) {
+        self.trash_days = ::std::option::Option::None;
+    }
+
+    pub fn has_trash_days(&self) -> bool {
+        self.trash_days.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trash_days(&mut self, v: u64) {
+        self.trash_days = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_trash_days(&self) -> u64 {
+        self.trash_days.unwrap_or(0)
+    }
+
+    fn get_trash_days_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.trash_days
+    }
+
+    fn mut_trash_days_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.trash_days
+    }
+
+    // repeated .void.Op undo = 6;
+
+    pub fn clear_undo(&mut self) {
+        self.undo.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_undo(&mut self, v: ::protobuf::RepeatedField<Op>) {
+        self.undo = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_undo(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.undo
+    }
+
+    // Take field
+    pub fn take_undo(&mut self) -> ::protobuf::RepeatedField<Op> {
+        ::std::mem::replace(&mut self.undo, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_undo(&self) -> &[Op] {
+        &self.undo
+    }
+
+    fn get_undo_for_reflect(&self) -> &::protobuf::RepeatedField<Op> {
+        &self.undo
+    }
+
+    fn mut_undo_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.undo
+    }
+
+    // repeated .void.Op redo = 7;
+
+    pub fn clear_redo(&mut self) {
+        self.redo.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_redo(&mut self, v: ::protobuf::RepeatedField<Op>) {
+        self.redo = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_redo(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.redo
+    }
+
+    // Take field
+    pub fn take_redo(&mut self) -> ::protobuf::RepeatedField<Op> {
+        ::std::mem::replace(&mut self.redo, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_redo(&self) -> &[Op] {
+        &self.redo
+    }
+
+    fn get_redo_for_reflect(&self) -> &::protobuf::RepeatedField<Op> {
+        &self.redo
+    }
+
+    fn mut_redo_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Op> {
+        &mut self.redo
+    }
 }
 
 impl ::protobuf::Message for Screen {
@@ -2089,6 +4114,22 @@ impl ::protobuf::Message for Screen {
                 3 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.arrows)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.trash)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.trash_days = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.undo)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.redo)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2112,6 +4153,21 @@ impl ::protobuf::Message for Screen {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in &self.trash {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.trash_days {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.undo {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.redo {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2131,6 +4187,24 @@ impl ::protobuf::Message for Screen {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        for v in &self.trash {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.trash_days {
+            os.write_uint64(5, v)?;
+        };
+        for v in &self.undo {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.redo {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2184,6 +4258,26 @@ impl ::protobuf::MessageStatic for Screen {
                     Screen::get_arrows_for_reflect,
                     Screen::mut_arrows_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TrashEntry>>(
+                    "trash",
+                    Screen::get_trash_for_reflect,
+                    Screen::mut_trash_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "trash_days",
+                    Screen::get_trash_days_for_reflect,
+                    Screen::mut_trash_days_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Op>>(
+                    "undo",
+                    Screen::get_undo_for_reflect,
+                    Screen::mut_undo_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Op>>(
+                    "redo",
+                    Screen::get_redo_for_reflect,
+                    Screen::mut_redo_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Screen>(
                     "Screen",
                     fields,
@@ -2199,6 +4293,10 @@ impl ::protobuf::Clear for Screen {
         self.clear_nodes();
         self.clear_max_id();
         self.clear_arrows();
+        self.clear_trash();
+        self.clear_trash_days();
+        self.clear_undo();
+        self.clear_redo();
         self.unknown_fields.clear();
     }
 }
@@ -2218,186 +4316,102 @@ impl ::protobuf::reflect::ProtobufValue for Screen {
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x18, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x2f, 0x64, 0x61, 0x74, 0x61, 0x5f, 0x6d,
     0x6f, 0x64, 0x65, 0x6c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x04, 0x76, 0x6f, 0x69, 0x64,
-    0x22, 0x2d, 0x0a, 0x03, 0x54, 0x61, 0x67, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01,
-    0x20, 0x02, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c,
-    0x75, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22,
-    0x29, 0x0a, 0x03, 0x47, 0x70, 0x73, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x61, 0x74, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x02, 0x52, 0x03, 0x6c, 0x61, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x6c, 0x6f, 0x6e, 0x18,
-    0x02, 0x20, 0x02, 0x28, 0x02, 0x52, 0x03, 0x6c, 0x6f, 0x6e, 0x22, 0xa1, 0x01, 0x0a, 0x04, 0x4d,
-    0x65, 0x74, 0x61, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x04, 0x52, 0x05, 0x63, 0x74, 0x69, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x6d, 0x74, 0x69,
-    0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x52, 0x05, 0x6d, 0x74, 0x69, 0x6d, 0x65, 0x12,
-    0x1f, 0x0a, 0x0b, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x05,
-    0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x54, 0x69, 0x6d, 0x65,
-    0x12, 0x1b, 0x0a, 0x03, 0x67, 0x70, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x09, 0x2e,
-    0x76, 0x6f, 0x69, 0x64, 0x2e, 0x47, 0x70, 0x73, 0x52, 0x03, 0x67, 0x70, 0x73, 0x12, 0x1d, 0x0a,
-    0x04, 0x74, 0x61, 0x67, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x76, 0x6f,
-    0x69, 0x64, 0x2e, 0x54, 0x61, 0x67, 0x52, 0x04, 0x74, 0x61, 0x67, 0x73, 0x12, 0x10, 0x0a, 0x03,
-    0x64, 0x75, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x64, 0x75, 0x65, 0x22, 0xda,
-    0x02, 0x0a, 0x04, 0x4e, 0x6f, 0x64, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x1e, 0x0a, 0x04, 0x6d, 0x65, 0x74, 0x61, 0x18,
-    0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4d, 0x65, 0x74,
-    0x61, 0x52, 0x04, 0x6d, 0x65, 0x74, 0x61, 0x12, 0x12, 0x0a, 0x04, 0x74, 0x65, 0x78, 0x74, 0x18,
-    0x03, 0x20, 0x02, 0x28, 0x09, 0x52, 0x04, 0x74, 0x65, 0x78, 0x74, 0x12, 0x1a, 0x0a, 0x08, 0x63,
-    0x68, 0x69, 0x6c, 0x64, 0x72, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x03, 0x28, 0x04, 0x52, 0x08, 0x63,
-    0x68, 0x69, 0x6c, 0x64, 0x72, 0x65, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x63, 0x6f, 0x6c, 0x6c, 0x61,
-    0x70, 0x73, 0x65, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x08, 0x52, 0x09, 0x63, 0x6f, 0x6c, 0x6c,
-    0x61, 0x70, 0x73, 0x65, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x73, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x65,
-    0x6e, 0x18, 0x06, 0x20, 0x02, 0x28, 0x08, 0x52, 0x08, 0x73, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x65,
-    0x6e, 0x12, 0x23, 0x0a, 0x0d, 0x68, 0x69, 0x64, 0x65, 0x5f, 0x73, 0x74, 0x72, 0x69, 0x63, 0x6b,
-    0x65, 0x6e, 0x18, 0x07, 0x20, 0x02, 0x28, 0x08, 0x52, 0x0c, 0x68, 0x69, 0x64, 0x65, 0x53, 0x74,
-    0x72, 0x69, 0x63, 0x6b, 0x65, 0x6e, 0x12, 0x0c, 0x0a, 0x01, 0x78, 0x18, 0x08, 0x20, 0x02, 0x28,
-    0x0d, 0x52, 0x01, 0x78, 0x12, 0x0c, 0x0a, 0x01, 0x79, 0x18, 0x09, 0x20, 0x02, 0x28, 0x0d, 0x52,
-    0x01, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x65, 0x64, 0x18, 0x0a,
-    0x20, 0x02, 0x28, 0x08, 0x52, 0x08, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x65, 0x64, 0x12, 0x1b,
-    0x0a, 0x09, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x0b, 0x20, 0x02, 0x28,
-    0x04, 0x52, 0x08, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x66,
-    0x72, 0x65, 0x65, 0x5f, 0x74, 0x65, 0x78, 0x74, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
-    0x66, 0x72, 0x65, 0x65, 0x54, 0x65, 0x78, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x61, 0x75, 0x74, 0x6f,
-    0x5f, 0x61, 0x72, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x0d, 0x20, 0x02, 0x28, 0x08, 0x52, 0x0b,
-    0x61, 0x75, 0x74, 0x6f, 0x41, 0x72, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x22, 0x3d, 0x0a, 0x05, 0x41,
-    0x72, 0x72, 0x6f, 0x77, 0x12, 0x1b, 0x0a, 0x09, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x6e, 0x6f, 0x64,
-    0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x52, 0x08, 0x66, 0x72, 0x6f, 0x6d, 0x4e, 0x6f, 0x64,
-    0x65, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x6f, 0x5f, 0x6e, 0x6f, 0x64, 0x65, 0x18, 0x02, 0x20, 0x02,
-    0x28, 0x04, 0x52, 0x06, 0x74, 0x6f, 0x4e, 0x6f, 0x64, 0x65, 0x22, 0x66, 0x0a, 0x06, 0x53, 0x63,
-    0x72, 0x65, 0x65, 0x6e, 0x12, 0x20, 0x0a, 0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x01, 0x20,
-    0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x52,
-    0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x12, 0x15, 0x0a, 0x06, 0x6d, 0x61, 0x78, 0x5f, 0x69, 0x64,
-    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x52, 0x05, 0x6d, 0x61, 0x78, 0x49, 0x64, 0x12, 0x23, 0x0a,
-    0x06, 0x61, 0x72, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e,
-    0x76, 0x6f, 0x69, 0x64, 0x2e, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x52, 0x06, 0x61, 0x72, 0x72, 0x6f,
-    0x77, 0x73, 0x4a, 0xb8, 0x10, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x30, 0x01, 0x0a, 0x08, 0x0a,
-    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x08,
-    0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x07, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x05, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x05,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x12, 0x15,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x18, 0x19, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x06, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x06, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x06, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0c, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x0a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a,
-    0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x17, 0x18,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x0b, 0x11, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x0b, 0x17, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0e, 0x00, 0x15,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x0c, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x0f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x0f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x0f, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0f,
-    0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x10, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12,
-    0x03, 0x11, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x11,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x11, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11, 0x12, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x12, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x03, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x06,
-    0x12, 0x03, 0x12, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x12, 0x0f, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x12, 0x15,
-    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x04, 0x06, 0x12, 0x03, 0x13, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x13, 0x0f, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x13, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03,
-    0x14, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x04, 0x12, 0x03, 0x14, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x14, 0x12, 0x15, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x14, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x03, 0x12, 0x04, 0x17, 0x00, 0x25, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
-    0x17, 0x08, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x18, 0x02, 0x19,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01,
-    0x12, 0x03, 0x19, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x19, 0x0b,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x10, 0x14, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19, 0x17, 0x18, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
-    0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x1a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a,
-    0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x1b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12,
-    0x03, 0x1c, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x04, 0x12, 0x03, 0x1c,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x0f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1c, 0x10, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1c, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x03, 0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x05, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05,
-    0x12, 0x03, 0x1d, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03,
-    0x1d, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x1d, 0x1b,
-    0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x22, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x06, 0x05, 0x12, 0x03, 0x1e, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x1e, 0x10, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06,
-    0x03, 0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x07, 0x12, 0x03,
-    0x1f, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x04, 0x12, 0x03, 0x1f, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x05, 0x12, 0x03, 0x1f, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x07, 0x03, 0x12, 0x03, 0x1f, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x03, 0x02, 0x08, 0x12, 0x03, 0x20, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08,
-    0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x05, 0x12,
-    0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x01, 0x12, 0x03, 0x20,
-    0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x03, 0x12, 0x03, 0x20, 0x16, 0x17,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x09, 0x12, 0x03, 0x21, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x09, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x09, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x09, 0x01, 0x12, 0x03, 0x21, 0x10, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x03,
-    0x12, 0x03, 0x21, 0x1b, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0a, 0x12, 0x03, 0x22,
-    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x22, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x22, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x22, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x22, 0x1e, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03,
-    0x02, 0x0b, 0x12, 0x03, 0x23, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x04,
-    0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x05, 0x12, 0x03,
-    0x23, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x23, 0x12,
-    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x23, 0x1e, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0c, 0x12, 0x03, 0x24, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x0c, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x0c, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c,
-    0x01, 0x12, 0x03, 0x24, 0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x03, 0x12,
-    0x03, 0x24, 0x1f, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x27, 0x00, 0x2a, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x27, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x28, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x1e,
-    0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x1e, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x29, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x29, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x2c, 0x00,
-    0x30, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x2c, 0x08, 0x0e, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x2d, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x2d, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x2d, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x1d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02,
-    0x12, 0x03, 0x2f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03,
-    0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x06, 0x12, 0x03, 0x2f, 0x0b,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x11, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x1a, 0x1b,
+    0x22, 0x29, 0x0a, 0x03, 0x54, 0x61, 0x67, 0x12, 0x0f, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x09, 0x42, 0x02, 0x18, 0x00, 0x12, 0x11, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75,
+    0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x42, 0x02, 0x18, 0x00, 0x22, 0x27, 0x0a, 0x03, 0x47,
+    0x70, 0x73, 0x12, 0x0f, 0x0a, 0x03, 0x6c, 0x61, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x02, 0x42,
+    0x02, 0x18, 0x00, 0x12, 0x0f, 0x0a, 0x03, 0x6c, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x02,
+    0x42, 0x02, 0x18, 0x00, 0x22, 0x32, 0x0a, 0x0b, 0x4d, 0x65, 0x61, 0x73, 0x75, 0x72, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x12, 0x10, 0x0a, 0x04, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x11, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x01, 0x42, 0x02, 0x18, 0x00, 0x22, 0xd5, 0x01, 0x0a, 0x04, 0x4d, 0x65, 0x74,
+    0x61, 0x12, 0x11, 0x0a, 0x05, 0x63, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x42, 0x02, 0x18, 0x00, 0x12, 0x11, 0x0a, 0x05, 0x6d, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x17, 0x0a, 0x0b, 0x66, 0x69, 0x6e, 0x69, 0x73,
+    0x68, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00,
+    0x12, 0x1a, 0x0a, 0x03, 0x67, 0x70, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x09, 0x2e,
+    0x76, 0x6f, 0x69, 0x64, 0x2e, 0x47, 0x70, 0x73, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1b, 0x0a, 0x04,
+    0x74, 0x61, 0x67, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x09, 0x2e, 0x76, 0x6f, 0x69,
+    0x64, 0x2e, 0x54, 0x61, 0x67, 0x42, 0x02, 0x18, 0x00, 0x12, 0x0f, 0x0a, 0x03, 0x64, 0x75, 0x65,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x2b, 0x0a, 0x0c, 0x6d, 0x65,
+    0x61, 0x73, 0x75, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x11, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4d, 0x65, 0x61, 0x73, 0x75, 0x72, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x42, 0x02, 0x18, 0x00, 0x12, 0x17, 0x0a, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x6c,
+    0x65, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00,
+    0x22, 0xa0, 0x02, 0x0a, 0x04, 0x4e, 0x6f, 0x64, 0x65, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1c, 0x0a, 0x04, 0x6d, 0x65, 0x74,
+    0x61, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4d,
+    0x65, 0x74, 0x61, 0x42, 0x02, 0x18, 0x00, 0x12, 0x10, 0x0a, 0x04, 0x74, 0x65, 0x78, 0x74, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x09, 0x42, 0x02, 0x18, 0x00, 0x12, 0x14, 0x0a, 0x08, 0x63, 0x68, 0x69,
+    0x6c, 0x64, 0x72, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x03, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12,
+    0x15, 0x0a, 0x09, 0x63, 0x6f, 0x6c, 0x6c, 0x61, 0x70, 0x73, 0x65, 0x64, 0x18, 0x05, 0x20, 0x02,
+    0x28, 0x08, 0x42, 0x02, 0x18, 0x00, 0x12, 0x14, 0x0a, 0x08, 0x73, 0x74, 0x72, 0x69, 0x63, 0x6b,
+    0x65, 0x6e, 0x18, 0x06, 0x20, 0x02, 0x28, 0x08, 0x42, 0x02, 0x18, 0x00, 0x12, 0x19, 0x0a, 0x0d,
+    0x68, 0x69, 0x64, 0x65, 0x5f, 0x73, 0x74, 0x72, 0x69, 0x63, 0x6b, 0x65, 0x6e, 0x18, 0x07, 0x20,
+    0x02, 0x28, 0x08, 0x42, 0x02, 0x18, 0x00, 0x12, 0x0d, 0x0a, 0x01, 0x78, 0x18, 0x08, 0x20, 0x02,
+    0x28, 0x0d, 0x42, 0x02, 0x18, 0x00, 0x12, 0x0d, 0x0a, 0x01, 0x79, 0x18, 0x09, 0x20, 0x02, 0x28,
+    0x0d, 0x42, 0x02, 0x18, 0x00, 0x12, 0x14, 0x0a, 0x08, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x18, 0x0a, 0x20, 0x02, 0x28, 0x08, 0x42, 0x02, 0x18, 0x00, 0x12, 0x15, 0x0a, 0x09, 0x70,
+    0x61, 0x72, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x0b, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02,
+    0x18, 0x00, 0x12, 0x15, 0x0a, 0x09, 0x66, 0x72, 0x65, 0x65, 0x5f, 0x74, 0x65, 0x78, 0x74, 0x18,
+    0x0c, 0x20, 0x01, 0x28, 0x09, 0x42, 0x02, 0x18, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x61, 0x75, 0x74,
+    0x6f, 0x5f, 0x61, 0x72, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x0d, 0x20, 0x02, 0x28, 0x08, 0x42,
+    0x02, 0x18, 0x00, 0x22, 0x33, 0x0a, 0x05, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x12, 0x15, 0x0a, 0x09,
+    0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x6e, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x42,
+    0x02, 0x18, 0x00, 0x12, 0x13, 0x0a, 0x07, 0x74, 0x6f, 0x5f, 0x6e, 0x6f, 0x64, 0x65, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x22, 0x91, 0x01, 0x0a, 0x0a, 0x54, 0x72, 0x61,
+    0x73, 0x68, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x15, 0x0a, 0x09, 0x70, 0x61, 0x72, 0x65, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x14,
+    0x0a, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x42, 0x02, 0x18, 0x00, 0x12, 0x16, 0x0a, 0x0a, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f,
+    0x61, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1d, 0x0a, 0x05,
+    0x6e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f,
+    0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1f, 0x0a, 0x06, 0x61,
+    0x72, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x76, 0x6f,
+    0x69, 0x64, 0x2e, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x42, 0x02, 0x18, 0x00, 0x22, 0xdb, 0x01, 0x0a,
+    0x02, 0x4f, 0x70, 0x12, 0x1e, 0x0a, 0x04, 0x65, 0x64, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x0c, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x45, 0x64, 0x69, 0x74, 0x4f, 0x70, 0x42,
+    0x02, 0x18, 0x00, 0x12, 0x23, 0x0a, 0x06, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x53, 0x75, 0x62, 0x74, 0x72,
+    0x65, 0x65, 0x4f, 0x70, 0x42, 0x02, 0x18, 0x00, 0x12, 0x23, 0x0a, 0x06, 0x72, 0x65, 0x6d, 0x6f,
+    0x76, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e,
+    0x53, 0x75, 0x62, 0x74, 0x72, 0x65, 0x65, 0x4f, 0x70, 0x42, 0x02, 0x18, 0x00, 0x12, 0x22, 0x0a,
+    0x09, 0x61, 0x64, 0x64, 0x5f, 0x61, 0x72, 0x72, 0x6f, 0x77, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x0b, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x42, 0x02, 0x18,
+    0x00, 0x12, 0x25, 0x0a, 0x0c, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x5f, 0x61, 0x72, 0x72, 0x6f,
+    0x77, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x41,
+    0x72, 0x72, 0x6f, 0x77, 0x42, 0x02, 0x18, 0x00, 0x12, 0x20, 0x0a, 0x05, 0x67, 0x72, 0x6f, 0x75,
+    0x70, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4f,
+    0x70, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x42, 0x02, 0x18, 0x00, 0x22, 0x47, 0x0a, 0x06, 0x45, 0x64,
+    0x69, 0x74, 0x4f, 0x70, 0x12, 0x1e, 0x0a, 0x06, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65,
+    0x42, 0x02, 0x18, 0x00, 0x12, 0x1d, 0x0a, 0x05, 0x61, 0x66, 0x74, 0x65, 0x72, 0x18, 0x02, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x42,
+    0x02, 0x18, 0x00, 0x22, 0x78, 0x0a, 0x09, 0x53, 0x75, 0x62, 0x74, 0x72, 0x65, 0x65, 0x4f, 0x70,
+    0x12, 0x15, 0x0a, 0x09, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x14, 0x0a, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1d, 0x0a,
+    0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x76,
+    0x6f, 0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1f, 0x0a, 0x06,
+    0x61, 0x72, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x76,
+    0x6f, 0x69, 0x64, 0x2e, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x42, 0x02, 0x18, 0x00, 0x22, 0x24, 0x0a,
+    0x07, 0x4f, 0x70, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x19, 0x0a, 0x03, 0x6f, 0x70, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4f, 0x70, 0x42,
+    0x02, 0x18, 0x00, 0x22, 0xd1, 0x01, 0x0a, 0x06, 0x53, 0x63, 0x72, 0x65, 0x65, 0x6e, 0x12, 0x1d,
+    0x0a, 0x05, 0x6e, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e,
+    0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x42, 0x02, 0x18, 0x00, 0x12, 0x12, 0x0a,
+    0x06, 0x6d, 0x61, 0x78, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x42, 0x02, 0x18,
+    0x00, 0x12, 0x1f, 0x0a, 0x06, 0x61, 0x72, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x0b, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x41, 0x72, 0x72, 0x6f, 0x77, 0x42, 0x02,
+    0x18, 0x00, 0x12, 0x23, 0x0a, 0x05, 0x74, 0x72, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x10, 0x2e, 0x76, 0x6f, 0x69, 0x64, 0x2e, 0x54, 0x72, 0x61, 0x73, 0x68, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x42, 0x02, 0x18, 0x00, 0x12, 0x16, 0x0a, 0x0a, 0x74, 0x72, 0x61, 0x73, 0x68,
+    0x5f, 0x64, 0x61, 0x79, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x42, 0x02, 0x18, 0x00, 0x12,
+    0x1a, 0x0a, 0x04, 0x75, 0x6e, 0x64, 0x6f, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x08, 0x2e,
+    0x76, 0x6f, 0x69, 0x64, 0x2e, 0x4f, 0x70, 0x42, 0x02, 0x18, 0x00, 0x12, 0x1a, 0x0a, 0x04, 0x72,
+    0x65, 0x64, 0x6f, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x08, 0x2e, 0x76, 0x6f, 0x69, 0x64,
+    0x2e, 0x4f, 0x70, 0x42, 0x02, 0x18, 0x00, 0x42, 0x00, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x32,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
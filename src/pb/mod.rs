@@ -1,3 +1,5 @@
 mod data_model;
 
-pub use self::data_model::{Arrow, Gps, Meta, Node, Screen, Tag};
+pub use self::data_model::{
+    Arrow, EditOp, Gps, Measurement, Meta, Node, Op, OpGroup, Screen, SubtreeOp, Tag, TrashEntry,
+};
@@ -1,47 +1,375 @@
 use std::cmp;
 
+use time::{Date, Duration, OffsetDateTime, UtcOffset};
+
+use crate::query::PlotType;
+
+const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// bars are scaled from the lowest value, or zero if that is lower, to the highest
 pub fn plot_sparkline<T>(nums_in: Vec<T>) -> String
 where T: Into<i64> {
-    const BARS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
-
     let nums: Vec<_> = nums_in.into_iter().map(|n| n.into()).collect();
-    let max = nums.iter().max().unwrap();
+    let max = match nums.iter().max() {
+        Some(&max) => max,
+        None => return String::new(),
+    };
+    let min = cmp::min(0, *nums.iter().min().unwrap());
 
     let mut ret = String::new();
     for n in &nums {
-        if *max as i64 == 0 {
+        if max == min {
             ret.push(BARS[0]);
         } else {
-            let idx = (BARS.len() - 1) as i64 * n / max;
+            let idx = (BARS.len() - 1) as i64 * (n - min) / (max - min);
             ret.push(BARS[idx as usize]);
         }
     }
     ret
 }
 
-pub fn bounded_count_sparkline<T>(nums_in: Vec<T>, start: T, end: T, bars: usize) -> String
-where T: Into<i64> {
-    if bars == 0 {
-        return String::new();
-    }
+fn blank(bars: usize) -> String { " ".repeat(bars) }
 
-    let start = start.into();
-    let end = end.into();
-    let nums: Vec<_> = nums_in.into_iter().map(|n| n.into()).collect();
+// how many of nums fall in each of `bars` equal spans of start..end,
+// or None if there are none or the range is too short to split. nums
+// outside the range are counted in the first or last bucket.
+fn bucket_counts(nums: &[i64], start: i64, end: i64, bars: usize) -> Option<Vec<i64>> {
+    if bars == 0 || nums.is_empty() || end <= start {
+        return None;
+    }
     let step = (end - start) / bars as i64;
+    if step == 0 {
+        return None;
+    }
     let mut counts = vec![0; bars];
+    for &n in nums {
+        let idx = (cmp::max(n, start) - start) / step;
+        counts[cmp::min(idx as usize, bars - 1)] += 1;
+    }
+    Some(counts)
+}
+
+// where each of `bars` equal spans of start..end begins and ends
+fn bucket_bounds(start: i64, end: i64, bars: usize) -> Option<Vec<(i64, i64)>> {
+    let step = (end - start) / cmp::max(bars, 1) as i64;
+    if bars == 0 || step <= 0 {
+        return None;
+    }
+    let mut bounds: Vec<(i64, i64)> = (0..bars as i64)
+        .map(|i| (start + i * step, start + (i + 1) * step))
+        .collect();
+    // the last bucket takes up the remainder of the division
+    bounds[bars - 1].1 = end;
+    Some(bounds)
+}
+
+// how many of the spans from creation to an optional finish are
+// still open at each of the given times
+fn open_at(spans: &[(i64, Option<i64>)], times: &[i64]) -> Vec<i64> {
+    times
+        .iter()
+        .map(|&t| {
+            spans
+                .iter()
+                .filter(|&&(created, finished)| created <= t && finished.is_none_or(|f| f > t))
+                .count() as i64
+        })
+        .collect()
+}
+
+// what each of `bars` buckets over start..end comes to for the spans
+// from when nodes were created to when they were finished, if ever,
+// with None for the buckets of a burndown that lie ahead of now
+fn plot_values(
+    kind: PlotType,
+    spans: &[(i64, Option<i64>)],
+    bounds: &[(i64, i64)],
+    now: i64,
+) -> Vec<Option<i64>> {
+    let bars = bounds.len();
+    let (start, end) = (bounds[0].0, bounds[bars - 1].1);
+    let created: Vec<i64> = spans.iter().map(|s| s.0).filter(|&t| t >= start).collect();
+    let finished: Vec<i64> = spans
+        .iter()
+        .filter_map(|s| s.1)
+        .filter(|&t| t >= start)
+        .collect();
+    let counts =
+        |times: &[i64]| bucket_counts(times, start, end, bars).unwrap_or_else(|| vec![0; bars]);
+
+    let values = match kind {
+        PlotType::New => counts(&created),
+        // calendars are drawn by `calendar`, but count the same
+        PlotType::Done | PlotType::Calendar => counts(&finished),
+        PlotType::Net => {
+            let finished = counts(&finished);
+            counts(&created)
+                .into_iter()
+                .zip(finished)
+                .map(|(c, f)| c - f)
+                .collect()
+        }
+        PlotType::Cumulative => {
+            let mut total = 0;
+            counts(&finished)
+                .into_iter()
+                .map(|n| {
+                    total += n;
+                    total
+                })
+                .collect()
+        }
+        PlotType::Open => {
+            let ends: Vec<i64> = bounds.iter().map(|b| b.1).collect();
+            open_at(spans, &ends)
+        }
+        PlotType::Burndown => {
+            // what is left as of now, with the rest of the way to go blank
+            let ends: Vec<i64> = bounds
+                .iter()
+                .take_while(|b| b.0 <= now)
+                .map(|b| cmp::min(b.1, now))
+                .collect();
+            let mut values: Vec<Option<i64>> =
+                open_at(spans, &ends).into_iter().map(Some).collect();
+            values.resize(bars, None);
+            return values;
+        }
+    };
+    values.into_iter().map(Some).collect()
+}
+
+// a sparkline over start..end of the spans from when nodes were created
+// to when they were finished, if ever
+pub fn plot_spans(
+    kind: PlotType,
+    spans: &[(i64, Option<i64>)],
+    start: i64,
+    end: i64,
+    bars: usize,
+    now: i64,
+) -> String {
+    let bounds = match bucket_bounds(start, end, bars) {
+        Some(bounds) => bounds,
+        None => return blank(bars),
+    };
+    let values = plot_values(kind, spans, &bounds, now);
+    let known: Vec<i64> = values.iter().flatten().cloned().collect();
+    let ahead = bars - known.len();
+    plot_sparkline(known) + &blank(ahead)
+}
+
+// like plot_spans, but as a bar chart `height` rows tall, with the
+// highest and lowest values to the left and the dates buckets start
+// on below it
+pub fn chart_spans(
+    kind: PlotType,
+    spans: &[(i64, Option<i64>)],
+    start: i64,
+    end: i64,
+    bars: usize,
+    now: i64,
+    height: usize,
+) -> Vec<String> {
+    match bucket_bounds(start, end, bars) {
+        Some(bounds) => chart(&plot_values(kind, spans, &bounds, now), &bounds, height),
+        None => vec![blank(bars); height],
+    }
+}
+
+// bars are scaled like plot_sparkline's, with eight steps to a row
+fn chart(values: &[Option<i64>], bounds: &[(i64, i64)], height: usize) -> Vec<String> {
+    let height = cmp::max(height, 1);
+    let hi = values.iter().flatten().max().cloned().unwrap_or(0);
+    let lo = cmp::min(0, values.iter().flatten().min().cloned().unwrap_or(0));
+    let (hi_label, lo_label) = (hi.to_string(), lo.to_string());
+    let pad = cmp::max(hi_label.len(), lo_label.len());
+
+    let mut rows = vec![];
+    for row in 0..height {
+        let label = if row == 0 {
+            &hi_label
+        } else if row == height - 1 {
+            &lo_label
+        } else {
+            ""
+        };
+        let axis = if label.is_empty() { '│' } else { '┤' };
+        let mut line = format!("{:>pad$}{}", label, axis, pad = pad);
+        // the number of eighths of a row below this one
+        let floor = ((height - 1 - row) * 8) as i64;
+        for value in values {
+            line.push(match *value {
+                Some(v) if hi > lo => {
+                    let level = (v - lo) * (height * 8) as i64 / (hi - lo);
+                    BARS[(level - floor).clamp(0, 8) as usize]
+                }
+                _ => ' ',
+            });
+        }
+        rows.push(line);
+    }
+
+    // ticks under the buckets whose start dates fit below the chart
+    let span = bounds[bounds.len() - 1].1 - bounds[0].0;
+    let mut axis: Vec<char> = vec!['─'; bounds.len()];
+    let mut ticks: Vec<char> = vec![' '; bounds.len()];
+    let mut free = 0;
+    for (i, &(start, _)) in bounds.iter().enumerate() {
+        let label = tick_label(start, span);
+        let len = label.chars().count();
+        if i >= free && i + len <= bounds.len() {
+            axis[i] = '┬';
+            ticks.splice(i..i + len, label.chars());
+            free = i + len + 1;
+        }
+    }
+    rows.push(format!(
+        "{:>pad$}└{}",
+        "",
+        axis.into_iter().collect::<String>(),
+        pad = pad
+    ));
+    if free > 0 {
+        rows.push(format!(
+            "{:>pad$} {}",
+            "",
+            ticks.into_iter().collect::<String>(),
+            pad = pad
+        ));
+    }
+    rows
+}
 
-    if step == 0 || nums.is_empty() || end <= start {
-        return String::from_utf8(vec![b' '; bars]).unwrap();
+// the local month and day a bucket starts on, or the time of day if
+// the whole chart covers less than two days
+fn tick_label(secs: i64, span: i64) -> String {
+    let utc = OffsetDateTime::from_unix_timestamp(secs);
+    let local = utc.to_offset(UtcOffset::local_offset_at(utc));
+    if span < 2 * 24 * 60 * 60 {
+        local.format("%H:%M")
+    } else {
+        local.format("%m-%d")
     }
+}
 
-    let start = start as usize;
-    let step = step as usize;
+// a heatmap of how many things were finished on each of the days in
+// the last `weeks` weeks up to `now`, one row per weekday from monday
+// and one column per week, under the months the weeks start in. days
+// with none show as dots, and days yet to come as blanks.
+pub fn calendar(finished: &[i64], weeks: usize, now: i64) -> Vec<String> {
+    const SHADES: [char; 5] = ['·', '░', '▒', '▓', '█'];
+    const WEEKDAYS: [&str; 7] = ["M", " ", "W", " ", "F", " ", " "];
 
-    for &n in &nums {
-        let n = cmp::max(n as usize, start) as usize;
-        let idx = (n - start) / step;
-        counts[cmp::min(idx, bars - 1)] += 1;
+    let weeks = cmp::max(weeks, 1);
+    let today = local_date(now);
+    let today_n = i64::from(today.weekday().number_days_from_monday());
+    let mut counts = vec![[0; 7]; weeks];
+    for &t in finished {
+        let days_ago = (today - local_date(t)).whole_days();
+        if days_ago < 0 {
+            continue;
+        }
+        let weeks_ago = ((days_ago + 6 - today_n) / 7) as usize;
+        if weeks_ago < weeks {
+            let weekday = (today_n - days_ago).rem_euclid(7) as usize;
+            counts[weeks - 1 - weeks_ago][weekday] += 1;
+        }
     }
-    plot_sparkline(counts)
+    let most = counts.iter().flatten().max().cloned().unwrap_or(0);
+
+    // the month of each week's monday, labelled where it changes
+    let mut months: Vec<char> = vec![' '; weeks];
+    let mut free = 0;
+    let mut last_month = None;
+    for week in 0..weeks {
+        let monday = today - Duration::days(today_n + 7 * (weeks - 1 - week) as i64);
+        if last_month != Some(monday.month()) && week >= free && week + 3 <= weeks {
+            months.splice(week..week + 3, monday.format("%b").chars());
+            free = week + 4;
+        }
+        last_month = Some(monday.month());
+    }
+
+    let mut rows = vec![format!("  {}", months.into_iter().collect::<String>())];
+    for (weekday, label) in WEEKDAYS.iter().enumerate() {
+        let mut row = format!("{} ", label);
+        for (week, days) in counts.iter().enumerate() {
+            row.push(if week == weeks - 1 && weekday as i64 > today_n {
+                ' '
+            } else if days[weekday] == 0 {
+                SHADES[0]
+            } else {
+                // the busiest day is the darkest, and any day at all shows
+                let shade = (days[weekday] * 4 + most - 1) / most;
+                SHADES[shade as usize]
+            });
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+fn local_date(secs: i64) -> Date {
+    let utc = OffsetDateTime::from_unix_timestamp(secs);
+    utc.to_offset(UtcOffset::local_offset_at(utc)).date()
+}
+
+#[test]
+fn test_plots() {
+    assert_eq!(plot_sparkline(vec![0, 4, 8]), " ▄█");
+    assert_eq!(plot_sparkline(vec![-2, 0, 2]), " ▄█");
+    assert_eq!(plot_sparkline(Vec::<i64>::new()), "");
+    assert_eq!(
+        bucket_counts(&[0, 5, 12, 40], 0, 30, 3),
+        Some(vec![2, 1, 1])
+    );
+    assert_eq!(bucket_counts(&[1], 0, 2, 3), None);
+    assert_eq!(
+        bucket_bounds(0, 31, 3),
+        Some(vec![(0, 10), (10, 20), (20, 31)])
+    );
+    let spans = [(0, None), (5, Some(15)), (12, Some(30))];
+    assert_eq!(open_at(&spans, &[10, 20, 30]), vec![2, 2, 1]);
+
+    // four created over 0..40, two of them finished
+    let spans = [(0, Some(25)), (5, None), (15, Some(35)), (25, None)];
+    let plot = |kind, now| plot_spans(kind, &spans, 0, 40, 4, now);
+    assert_eq!(plot(PlotType::New, 40), "█▄▄ ");
+    assert_eq!(plot(PlotType::Done, 40), "  ██");
+    assert_eq!(plot(PlotType::Net, 40), "█▅▂ ");
+    assert_eq!(plot(PlotType::Cumulative, 40), "  ▄█");
+    assert_eq!(plot(PlotType::Open, 40), "▅██▅");
+    assert_eq!(plot(PlotType::Burndown, 22), "▅██ ");
+    assert_eq!(plot_spans(PlotType::Open, &spans, 40, 0, 4, 0), "    ");
+
+    // eight steps to a row, so a quarter of the highest is half a row
+    let day = 24 * 60 * 60;
+    let spans = [(0, None), (0, None), (0, None), (0, Some(day)), (day, None)];
+    let chart = chart_spans(PlotType::New, &spans, 0, 8 * day, 8, 8 * day, 2);
+    assert_eq!(chart[0], "4┤█       ");
+    assert_eq!(chart[1], "0┤█▄      ");
+    assert_eq!(chart[2], " └┬───────");
+    assert_eq!(chart[3], format!("  {}   ", tick_label(0, 8 * day)));
+    assert_eq!(chart.len(), 4);
+    // too few buckets for a date to fit below
+    assert_eq!(
+        chart_spans(PlotType::Done, &spans, 0, 2 * day, 2, 2 * day, 2),
+        vec!["1┤ █", "0┤ █", " └──"]
+    );
+
+    // a wednesday, with two finished the day before and one on each
+    // of this and last week's wednesdays
+    let day = 24 * 60 * 60;
+    let now = crate::dateparse::local_timestamp(
+        Date::try_from_ymd(2026, 10, 14).unwrap(),
+        time::Time::try_from_hms(12, 0, 0).unwrap(),
+    )
+    .unwrap() as i64;
+    let finished = [now, now - day, now - day, now - 7 * day, now + day];
+    assert_eq!(
+        calendar(&finished, 2, now),
+        vec!["    ", "M ··", "  ·█", "W ▒▒", "  · ", "F · ", "  · ", "  · "]
+    );
+    assert_eq!(calendar(&[], 4, now)[0], "  Sep ");
 }
@@ -0,0 +1,427 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use regex::Regex;
+
+use crate::{
+    dateparse,
+    dateparse::{dateparse_due, DateError},
+    re_matches,
+    tagexpr::TagExpr,
+    Node, NodeID, TagDB,
+};
+
+// the filters and plot settings that may be embedded in
+// a node's text, like `#tagged=work #open #since=7d`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Query {
+    pub tagged: Vec<String>,
+    pub q: Option<TagExpr>,
+    // match tags inherited from ancestors too
+    pub inherit: bool,
+    pub done: bool,
+    pub open: bool,
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub due_before: Option<u64>,
+    pub due_after: Option<u64>,
+    pub sort: Option<SortKey>,
+    pub sort_desc: bool,
+    pub rev: bool,
+    pub limit: Option<usize>,
+    pub plot: Option<PlotType>,
+    pub n: Option<usize>,
+    pub h: Option<usize>,
+    // dates that couldn't be read, which are left out of the query
+    pub errors: Vec<String>,
+}
+
+// what `#sort=` and `:sort` order nodes by. anything that isn't a
+// built in key is the value of a `key=value` tag, like prio, which
+// may be inherited from an ancestor
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortKey {
+    Due,
+    Ctime,
+    Mtime,
+    // when the node was finished
+    Done,
+    Alpha,
+    Tag(String),
+}
+
+// what `#plot=` draws in place of a query node's text, over the
+// nodes below the query's matches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlotType {
+    // created, finished, or created minus finished in each bucket
+    New,
+    Done,
+    Net,
+    // finished since the start of the plot by the end of each bucket
+    Cumulative,
+    // still open at the end of each bucket
+    Open,
+    // like open, from the start of the plot to the node's due date
+    Burndown,
+    // finished on each day of the last year, by weekday and week
+    Calendar,
+}
+
+impl PlotType {
+    fn parse(kind: &str) -> Option<PlotType> {
+        match kind {
+            "new" => Some(PlotType::New),
+            "done" => Some(PlotType::Done),
+            "net" => Some(PlotType::Net),
+            "cumulative" => Some(PlotType::Cumulative),
+            "open" => Some(PlotType::Open),
+            "burndown" => Some(PlotType::Burndown),
+            "calendar" => Some(PlotType::Calendar),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+enum SortValue {
+    Number(f64),
+    Text(String),
+}
+
+impl SortKey {
+    pub fn parse(key: &str) -> SortKey {
+        match key {
+            "due" => SortKey::Due,
+            "ctime" => SortKey::Ctime,
+            "mtime" => SortKey::Mtime,
+            "done" => SortKey::Done,
+            "alpha" => SortKey::Alpha,
+            _ => SortKey::Tag(key.to_owned()),
+        }
+    }
+
+    fn value(
+        &self,
+        node_id: NodeID,
+        nodes: &HashMap<NodeID, Node>,
+        tag_db: &TagDB,
+    ) -> Option<SortValue> {
+        let node = nodes.get(&node_id)?;
+        let time = |t: Option<u64>| t.map(|t| SortValue::Number(t as f64));
+        match *self {
+            SortKey::Due => time(node.meta.due),
+            SortKey::Ctime => time(Some(node.meta.ctime)),
+            SortKey::Mtime => time(Some(node.meta.mtime)),
+            SortKey::Done => time(node.meta.finish_time),
+            SortKey::Alpha => Some(SortValue::Text(node.content.to_lowercase())),
+            SortKey::Tag(ref key) => {
+                let prefix = format!("{}=", key);
+                let value = tag_db
+                    .effective_tags(node_id, nodes)
+                    .into_iter()
+                    .filter_map(|tag| tag.strip_prefix(&prefix).map(str::to_owned))
+                    .min()?;
+                Some(match value.parse() {
+                    Ok(number) => SortValue::Number(number),
+                    Err(_) => SortValue::Text(value),
+                })
+            }
+        }
+    }
+
+    // orders nodes by this key, ascending unless desc. nodes
+    // without the key go last either way
+    pub fn sort(
+        &self,
+        node_ids: &mut Vec<NodeID>,
+        desc: bool,
+        nodes: &HashMap<NodeID, Node>,
+        tag_db: &TagDB,
+    ) {
+        let mut keyed: Vec<(Option<SortValue>, NodeID)> = node_ids
+            .iter()
+            .map(|&id| (self.value(id, nodes, tag_db), id))
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) => {
+                let ord = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+                if desc {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            }
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        });
+        *node_ids = keyed.into_iter().map(|(_, id)| id).collect();
+    }
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Query {
+        lazy_static! {
+            //// general subtree population and modification
+            // limit shows the top N results.
+            static ref RE_LIMIT: Regex = Regex::new(r"#limit=(\d+)").unwrap();
+            static ref RE_TAGGED: Regex = Regex::new(r"#tagged=(\S+)").unwrap();
+            // a boolean tag query runs up to the next tag, or to the end of
+            // the expression if text follows it, like
+            // #q=(work|oss) & !blocked & prio>=3 #open
+            static ref RE_Q: Regex = Regex::new(r"#q=([^#]*)").unwrap();
+            // sort by a key, ascending unless followed by :desc
+            static ref RE_SORT: Regex = Regex::new(r"#sort=([^\s:]+)(:\S*)?").unwrap();
+            static ref RE_INHERIT: Regex = Regex::new(r"#inherit\b").unwrap();
+            static ref RE_REV: Regex = Regex::new(r"#rev\b").unwrap();
+            static ref RE_DONE: Regex = Regex::new(r"#done\b").unwrap();
+            static ref RE_OPEN: Regex = Regex::new(r"#open\b").unwrap();
+            // since defaults to last week
+            static ref RE_SINCE: Regex = Regex::new(r"#since=(\S+)").unwrap();
+            // until defaults until now
+            static ref RE_UNTIL: Regex = Regex::new(r"#until=(\S+)").unwrap();
+            // due within or after some time from now, like #due<3d
+            static ref RE_DUE_BEFORE: Regex = Regex::new(r"#due<(\S+)").unwrap();
+            static ref RE_DUE_AFTER: Regex = Regex::new(r"#due>(\S+)").unwrap();
+
+            //// plot specific
+            // plot can be {new,done,net,cumulative,open,burndown,calendar}
+            static ref RE_PLOT: Regex = Regex::new(r"#plot=(\S+)").unwrap();
+            // n is the number of buckets
+            static ref RE_N: Regex = Regex::new(r"#n=(\d+)").unwrap();
+            // h is the height of the chart in rows, if more than a line
+            static ref RE_H: Regex = Regex::new(r"#h=(\d+)").unwrap();
+        }
+
+        let mut errors = vec![];
+        let mut date = |re: &Regex, parse: fn(&str) -> Result<u64, DateError>| {
+            let date = re_matches::<String>(re, text).into_iter().next()?;
+            parse(&date).map_err(|e| errors.push(e.to_string())).ok()
+        };
+        let since = date(&RE_SINCE, dateparse);
+        let until = date(&RE_UNTIL, dateparse);
+        let due_before = date(&RE_DUE_BEFORE, dateparse_due);
+        let due_after = date(&RE_DUE_AFTER, dateparse_due);
+        let mut sort_desc = false;
+        let sort = RE_SORT.captures(text).map(|caps| {
+            match caps.get(2).map(|m| m.as_str()) {
+                None | Some(":asc") => {}
+                Some(":desc") => sort_desc = true,
+                Some(order) => errors.push(format!("can't sort in {:?} order", &order[1..])),
+            }
+            SortKey::parse(&caps[1])
+        });
+        let plot = RE_PLOT.captures(text).and_then(|caps| {
+            let plot = PlotType::parse(&caps[1]);
+            if plot.is_none() {
+                errors.push(format!("can't plot {:?}", &caps[1]));
+            }
+            plot
+        });
+        let q = RE_Q.captures(text).and_then(|caps| {
+            TagExpr::parse_prefix(caps[1].trim())
+                .map(|(expr, _)| expr)
+                .map_err(|e| errors.push(e))
+                .ok()
+        });
+
+        Query {
+            tagged: re_matches(&RE_TAGGED, text),
+            q,
+            inherit: RE_INHERIT.is_match(text),
+            done: RE_DONE.is_match(text),
+            open: RE_OPEN.is_match(text),
+            since,
+            until,
+            due_before,
+            due_after,
+            sort,
+            sort_desc,
+            rev: RE_REV.is_match(text),
+            limit: re_matches(&RE_LIMIT, text).first().cloned(),
+            plot,
+            n: re_matches(&RE_N, text).first().cloned(),
+            h: re_matches(&RE_H, text).first().cloned(),
+            errors,
+        }
+    }
+
+    // nodes carrying every `#tagged=` tag and matching `#q=`,
+    // or None if there are neither
+    pub fn tagged_nodes(
+        &self,
+        tag_db: &TagDB,
+        nodes: &HashMap<NodeID, Node>,
+    ) -> Option<Vec<NodeID>> {
+        if self.tagged.is_empty() && self.q.is_none() {
+            return None;
+        }
+        let mut tagged_nodes: Vec<NodeID> = if self.inherit {
+            nodes
+                .keys()
+                .cloned()
+                .filter(|&id| {
+                    let tags = tag_db.effective_tags(id, nodes);
+                    id != 0
+                        && self.tagged.iter().all(|tag| tags.contains(tag))
+                        && self.q.as_ref().is_none_or(|q| q.matches(&tags))
+                })
+                .collect()
+        } else {
+            // for tagged queries, AND queries together
+            let mut tagged_nodes = self.q.as_ref().map(|q| q.eval(tag_db));
+            for tag in &self.tagged {
+                let nodes = tag_db.tag_to_nodes(tag).into_iter().collect();
+                tagged_nodes = Some(match tagged_nodes {
+                    Some(acc) => acc.intersection(&nodes).cloned().collect(),
+                    None => nodes,
+                });
+            }
+            tagged_nodes.unwrap_or_default().into_iter().collect()
+        };
+        tagged_nodes.sort();
+        Some(tagged_nodes)
+    }
+
+    // keeps the nodes that pass the filters, then applies #sort, #rev and #limit
+    pub fn filter(
+        &self,
+        node_ids: Vec<NodeID>,
+        nodes: &HashMap<NodeID, Node>,
+        tag_db: &TagDB,
+    ) -> Vec<NodeID> {
+        let mut ret: Vec<NodeID> = node_ids
+            .into_iter()
+            .filter(|id| {
+                nodes.get(id).is_some_and(|n| {
+                    (n.stricken || !self.done)
+                        && (!n.stricken || !self.open)
+                        && self.since.is_none_or(|cutoff| n.meta.mtime >= cutoff)
+                        && self.until.is_none_or(|cutoff| n.meta.mtime <= cutoff)
+                        && self
+                            .due_before
+                            .is_none_or(|cutoff| n.meta.due.is_some_and(|due| due <= cutoff))
+                        && self
+                            .due_after
+                            .is_none_or(|cutoff| n.meta.due.is_some_and(|due| due >= cutoff))
+                })
+            })
+            .collect();
+        if let Some(ref sort) = self.sort {
+            sort.sort(&mut ret, self.sort_desc, nodes, tag_db);
+        }
+        if self.rev {
+            ret.reverse();
+        }
+        if let Some(limit) = self.limit {
+            ret.truncate(limit);
+        }
+        ret
+    }
+}
+
+#[test]
+fn test_query() {
+    let query = Query::parse("#tagged=work #tagged=home #open #rev #limit=1 #plot=done");
+    assert_eq!(query.tagged, vec!["work".to_owned(), "home".to_owned()]);
+    assert!(query.open && query.rev && !query.done);
+    assert_eq!(query.limit, Some(1));
+    assert_eq!(query.plot, Some(PlotType::Done));
+    assert_eq!(
+        Query::parse("#plot=pie").errors,
+        vec!["can't plot \"pie\"".to_owned()]
+    );
+    assert_eq!(
+        Query::parse("#since=2026-02-30 #due<soon").errors,
+        vec![
+            "\"2026-02-30\" is not a valid date".to_owned(),
+            "can't read \"soon\" as a date".to_owned()
+        ]
+    );
+
+    let mut nodes = HashMap::new();
+    for id in 1..=3 {
+        let node = Node {
+            id,
+            stricken: id == 2,
+            ..Node::default()
+        };
+        nodes.insert(id, node);
+    }
+    let mut tag_db = TagDB::default();
+    assert_eq!(query.filter(vec![1, 2, 3], &nodes, &tag_db), vec![3]);
+    assert_eq!(
+        Query::parse("#done").filter(vec![1, 2, 3], &nodes, &tag_db),
+        vec![2]
+    );
+    assert_eq!(
+        Query::parse("#until=1d").filter(vec![1, 2, 3], &nodes, &tag_db),
+        Vec::<NodeID>::new()
+    );
+    let now = crate::now().as_secs();
+    // since a weekday is the last one, not the next
+    let monday = Query::parse("#since=monday").since.unwrap();
+    assert!(monday <= now && monday + 60 * 60 * 24 * 7 > now);
+    nodes.get_mut(&1).unwrap().meta.due = Some(now + 60 * 60);
+    nodes.get_mut(&3).unwrap().meta.due = Some(now + 60 * 60 * 24 * 5);
+    assert_eq!(
+        Query::parse("#due<1d").filter(vec![1, 2, 3], &nodes, &tag_db),
+        vec![1]
+    );
+    assert_eq!(
+        Query::parse("#due>3d").filter(vec![1, 2, 3], &nodes, &tag_db),
+        vec![3]
+    );
+
+    for (id, content) in [(1, "b #prio=1"), (2, "C #prio=10"), (3, "a")] {
+        nodes.get_mut(&id).unwrap().content = content.to_owned();
+        tag_db.reindex(id, content.to_owned());
+    }
+    nodes.get_mut(&1).unwrap().meta.finish_time = Some(300);
+    nodes.get_mut(&2).unwrap().meta.finish_time = Some(200);
+    let sort = |text: &str| Query::parse(text).filter(vec![1, 2, 3], &nodes, &tag_db);
+    assert_eq!(sort("#sort=prio"), vec![1, 2, 3]);
+    assert_eq!(sort("#sort=prio:desc #limit=2"), vec![2, 1]);
+    assert_eq!(sort("#sort=alpha"), vec![3, 1, 2]);
+    assert_eq!(sort("#sort=due:desc"), vec![3, 1, 2]);
+    assert_eq!(sort("#sort=done"), vec![2, 1, 3]);
+    assert_eq!(sort("#sort=done:desc"), vec![1, 2, 3]);
+    // 3 inherits its prio from 1
+    nodes.get_mut(&3).unwrap().parent_id = 1;
+    let sort = |text: &str| Query::parse(text).filter(vec![1, 2, 3], &nodes, &tag_db);
+    assert_eq!(sort("#sort=prio:desc"), vec![2, 1, 3]);
+    assert_eq!(sort("#sort=prio"), vec![1, 3, 2]);
+    nodes.get_mut(&3).unwrap().parent_id = 0;
+    assert_eq!(
+        Query::parse("#sort=due:sideways").errors,
+        vec!["can't sort in \"sideways\" order".to_owned()]
+    );
+
+    let mut tag_db = TagDB::default();
+    tag_db.reindex(1, "a #work #home".to_owned());
+    tag_db.reindex(2, "b #work".to_owned());
+    assert_eq!(query.tagged_nodes(&tag_db, &nodes), Some(vec![1]));
+    assert_eq!(Query::parse("#open").tagged_nodes(&tag_db, &nodes), None);
+    assert_eq!(
+        Query::parse("#q=home | !work #open").tagged_nodes(&tag_db, &nodes),
+        Some(vec![1])
+    );
+    assert_eq!(
+        Query::parse("#tagged=work #q=!home").tagged_nodes(&tag_db, &nodes),
+        Some(vec![2])
+    );
+    // text after the expression is left alone
+    let titled = Query::parse("todo #q=work & !home list");
+    assert!(titled.errors.is_empty());
+    assert_eq!(titled.tagged_nodes(&tag_db, &nodes), Some(vec![2]));
+    nodes.get_mut(&3).unwrap().parent_id = 2;
+    assert_eq!(
+        Query::parse("#tagged=work #inherit").tagged_nodes(&tag_db, &nodes),
+        Some(vec![1, 2, 3])
+    );
+    assert_eq!(
+        Query::parse("#q=work & !home #inherit").tagged_nodes(&tag_db, &nodes),
+        Some(vec![2, 3])
+    );
+    let query = Query::parse("#q=(work #open");
+    assert_eq!(query.q, None);
+    assert_eq!(query.errors, vec!["bad query \"(work\": unmatched '('"]);
+}
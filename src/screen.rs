@@ -19,14 +19,36 @@ use termion::{
 };
 
 use rand::{self, Rng};
-use regex::Regex;
+use regex::{Captures, Regex};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    cost, dateparse, distances, logging, now, plot, random_fg_color, re_matches, serialization,
+    cmd::{self, Cmd},
+    cost, dateparse,
+    dateparse::dateparse_due,
+    distances, export,
+    formats::Outline,
+    import, logging, now, plot,
+    query::{PlotType, Query, SortKey},
+    random_fg_color, re_matches, serialization,
+    tagexpr::TagExpr,
+    trash::{self, TrashEntry, DEFAULT_TRASH_DAYS},
+    undo::{Op, UndoLog},
     Action, Config, Coords, Dir, Node, NodeID, Pack, TagDB,
 };
 
+// how deeply query nodes listed by other query nodes are expanded
+const MAX_QUERY_DEPTH: usize = 4;
+// how many query matches are drawn per frame
+const MAX_EPHEMERAL_NODES: usize = 2000;
+// how many tag completions are offered at once below the selected node
+const MAX_TAG_COMPLETIONS: usize = 8;
+// how many rows a `#h=` chart may take up
+const MAX_CHART_HEIGHT: usize = 24;
+
+// a line of months over one per weekday
+const CALENDAR_ROWS: u16 = 8;
+
 pub struct Screen {
     pub max_id: u64,
     pub nodes: HashMap<NodeID, Node>,
@@ -34,14 +56,22 @@ pub struct Screen {
     pub work_path: Option<String>,
     pub autosave_every: usize,
     pub config: Config,
+    // deleted subtrees, oldest first
+    pub trash: Vec<TrashEntry>,
+    // trash older than this is emptied on startup, 0 keeps it forever
+    pub trash_days: u64,
 
     // screen dimensions as detected during the current draw() cycle
     pub dims: Coords,
     pub is_test: bool,
 
+    // undo info, saved along with the trash
+    pub undo: UndoLog,
+
     // non-pub members are ephemeral
     drawing_root: NodeID,
     show_logs: bool,
+    show_calendar: bool,
     selected: Option<NodeID>,
     cut: Option<NodeID>,
     drawing_arrow: Option<NodeID>,
@@ -56,11 +86,8 @@ pub struct Screen {
     // when we drill down then pop up, we should go to last focus, stored here
     focus_stack: Vec<(NodeID, NodeID, u16)>,
     last_search: Option<(String, NodeID)>,
-
-    // undo info
-    undo_stack: Vec<NodeID>,
-    // needs to be separate, as recursive deletion of nodes causes ordering issues
-    undo_nodes: HashMap<NodeID, Node>,
+    // tags that Tab cycles through, and how often it was pressed
+    tag_completions: Option<(Vec<String>, usize)>,
 
     // nodes created specifically for rendering an augmented view
     ephemeral_nodes: HashMap<NodeID, Node>,
@@ -69,6 +96,10 @@ pub struct Screen {
     // ephemeral and normal nodes SHOULD occupy the same keyspace
     // but be exclusive.
     ephemeral_max_id: u64,
+    // the real nodes that ephemeral query matches stand in for
+    ephemeral_targets: HashMap<NodeID, NodeID>,
+    // and the reverse, by the query node they're listed under
+    ephemeral_ids: HashMap<(NodeID, NodeID), NodeID>,
 
     pub tag_db: TagDB,
 
@@ -86,6 +117,8 @@ impl Default for Screen {
         let mut screen = Screen {
             autosave_every: 25,
             config: Config::default(),
+            trash: vec![],
+            trash_days: DEFAULT_TRASH_DAYS,
             arrows: vec![],
             selected: None,
             cut: None,
@@ -94,6 +127,7 @@ impl Default for Screen {
             lookup: HashMap::new(),
             drawn_at: HashMap::new(),
             show_logs: false,
+            show_calendar: false,
             drawing_root: 0,
             stdout: None,
             dragging_from: None,
@@ -106,10 +140,12 @@ impl Default for Screen {
             focus_stack: vec![],
             is_test: false,
             last_search: None,
-            undo_stack: vec![],
-            undo_nodes: HashMap::new(),
+            tag_completions: None,
+            undo: UndoLog::default(),
             ephemeral_nodes: HashMap::new(),
             ephemeral_max_id: std::u64::MAX,
+            ephemeral_targets: HashMap::new(),
+            ephemeral_ids: HashMap::new(),
             tag_db: TagDB::default(),
             last_click_ms: 0,
             grapheme_cache: HashMap::new(),
@@ -167,71 +203,92 @@ impl Screen {
         self.nodes.get_mut(&k).map(|mut node| f(&mut node))
     }
 
-    // return of false signals to the caller that we are done in this view
     pub fn handle_event(&mut self, evt: Event) -> bool {
-        match self.config.map(evt) {
-            Some(e) => match e {
-                Action::LeftClick(x, y) => {
-                    let internal_coords = self.screen_to_internal_xy((x, y));
-                    self.click_screen(internal_coords)
-                }
-                Action::RightClick(..) => {
-                    self.pop_focus();
-                }
-                Action::Release(x, y) => {
-                    let internal_coords = self.screen_to_internal_xy((x, y));
-                    self.release(internal_coords)
-                }
-                // Write character to selection
-                Action::Char(c) if self.selected.is_some() => {
-                    self.append(c);
-                }
-                Action::Char('/') => {
-                    self.search_forward();
-                }
-                Action::Char('?') => {
-                    self.search_backward();
-                }
-                Action::Char(c) => {
-                    self.prefix_jump_to(c.to_string());
+        if let Some(action) = self.config.map(evt) {
+            self.handle_action(action)
+        } else {
+            warn!("received unknown input");
+            true
+        }
+    }
+
+    // return of false signals to the caller that we are done in this view
+    fn handle_action(&mut self, action: Action) -> bool {
+        if action != Action::CreateChild {
+            self.tag_completions = None;
+        }
+        match action {
+            // the calendar isn't part of the map
+            Action::LeftClick(_, y) | Action::Release(_, y)
+                if y > 1 && y < 2 + self.calendar_rows() => {}
+            Action::LeftClick(x, y) => {
+                let internal_coords = self.screen_to_internal_xy((x, y));
+                self.click_screen(internal_coords)
+            }
+            Action::RightClick(..) => {
+                self.pop_focus();
+            }
+            Action::Release(x, y) => {
+                let internal_coords = self.screen_to_internal_xy((x, y));
+                self.release(internal_coords)
+            }
+            // Write character to selection
+            Action::Char(c) if self.selected.is_some() => {
+                self.append(c);
+            }
+            Action::Char('/') => {
+                self.search_forward();
+            }
+            Action::Char('?') => {
+                self.search_backward();
+            }
+            Action::Char(c) => {
+                self.prefix_jump_to(c.to_string());
+            }
+            Action::Help => self.help(),
+            Action::UnselectRet => return self.unselect().is_some(),
+            Action::ScrollUp => self.scroll_up(),
+            Action::ScrollDown => self.scroll_down(),
+            Action::DeleteSelected => self.delete_selected(true),
+            Action::SelectUp => self.select_up(),
+            Action::SelectDown => self.select_down(),
+            Action::SelectLeft => self.select_left(),
+            Action::SelectRight => self.select_right(),
+            Action::EraseChar => self.backspace(),
+            Action::CreateSibling => self.create_sibling(),
+            Action::CreateChild => {
+                if !self.complete_tag() {
+                    self.create_child();
                 }
-                Action::Help => self.help(),
-                Action::UnselectRet => return self.unselect().is_some(),
-                Action::ScrollUp => self.scroll_up(),
-                Action::ScrollDown => self.scroll_down(),
-                Action::DeleteSelected => self.delete_selected(true),
-                Action::SelectUp => self.select_up(),
-                Action::SelectDown => self.select_down(),
-                Action::SelectLeft => self.select_left(),
-                Action::SelectRight => self.select_right(),
-                Action::EraseChar => self.backspace(),
-                Action::CreateSibling => self.create_sibling(),
-                Action::CreateChild => self.create_child(),
-                Action::CreateFreeNode => self.create_free_node(),
-                Action::ExecSelected => self.exec_selected(),
-                Action::DrillDown => self.drill_down(),
-                Action::PopUp => self.pop_focus(),
-                Action::PrefixJump => self.prefix_jump_prompt(),
-                Action::ToggleCompleted => self.toggle_stricken(),
-                Action::ToggleHideCompleted => self.toggle_hide_stricken(),
-                Action::Arrow => self.add_or_remove_arrow(),
-                Action::AutoArrange => self.toggle_auto_arrange(),
-                Action::ToggleCollapsed => self.toggle_collapsed(),
-                Action::Quit => return false,
-                Action::Save => self.save(),
-                Action::ToggleShowLogs => self.toggle_show_logs(),
-                Action::EnterCmd => self.enter_cmd(),
-                Action::FindTask => self.auto_task(),
-                Action::YankPasteNode => self.cut_paste(),
-                Action::RaiseSelected => self.raise_selected(),
-                Action::LowerSelected => self.lower_selected(),
-                Action::Search => self.search_forward(),
-                Action::UndoDelete => self.undo_delete(),
-                Action::SelectParent => self.select_parent(),
-                Action::SelectNextSibling => self.select_next_sibling(),
-                Action::SelectPrevSibling => self.select_prev_sibling(),
-            },
-            None => warn!("received unknown input"),
+            }
+            Action::CreateFreeNode => self.create_free_node(),
+            Action::ExecSelected => self.exec_selected(),
+            Action::DrillDown => self.drill_down(),
+            Action::PopUp => self.pop_focus(),
+            Action::PrefixJump => self.prefix_jump_prompt(),
+            Action::ToggleCompleted => self.toggle_stricken(),
+            Action::ToggleHideCompleted => self.toggle_hide_stricken(),
+            Action::Arrow => self.add_or_remove_arrow(),
+            Action::AutoArrange => self.toggle_auto_arrange(),
+            Action::ToggleCollapsed => self.toggle_collapsed(),
+            Action::Quit => return false,
+            Action::Save => self.save(),
+            Action::ToggleShowLogs => self.toggle_show_logs(),
+            Action::ToggleCalendar => self.show_calendar = !self.show_calendar,
+            Action::EnterCmd => return self.enter_cmd(String::new()),
+            Action::ExportMarkdown => return self.enter_cmd("export markdown ".to_owned()),
+            Action::FindTask => self.auto_task(),
+            Action::YankPasteNode => self.cut_paste(),
+            Action::RaiseSelected => self.raise_selected(),
+            Action::LowerSelected => self.lower_selected(),
+            Action::Search => self.search_forward(),
+            Action::Undo => self.undo(),
+            Action::Trash => self.browse_trash(),
+            Action::Tags => self.browse_tags(),
+            Action::Redo => self.redo(),
+            Action::SelectParent => self.select_parent(),
+            Action::SelectNextSibling => self.select_next_sibling(),
+            Action::SelectPrevSibling => self.select_prev_sibling(),
         }
         true
     }
@@ -265,6 +322,7 @@ impl Screen {
             // 3. set parent_id pointer
             // 4. bust grapheme cache
             let old_parent = self.parent(node_id).unwrap();
+            let before = self.snapshot(&[node_id, old_parent, parent_id]);
             self.with_node_mut_no_meta(old_parent, |op| op.children.retain(|c| c != &node_id))
                 .unwrap();
             self.with_node_mut_no_meta(parent_id, |np| np.children.push(node_id))
@@ -272,6 +330,7 @@ impl Screen {
             self.with_node_mut_no_meta(node_id, |s| s.parent_id = parent_id)
                 .unwrap();
             self.grapheme_cache.remove(&node_id);
+            self.record_edit(before);
         }
     }
 
@@ -286,7 +345,7 @@ impl Screen {
             let mut node = self.with_node(node_id, |n| n.clone()).unwrap();
             if node.stricken {
                 // pass
-            } else if node.content.contains("#task") {
+            } else if self.tag_db.node_tags(node_id).contains("task") {
                 task_roots.push(node.id);
             } else {
                 to_explore.append(&mut node.children);
@@ -314,15 +373,16 @@ impl Screen {
             return;
         }
 
-        // weight based on priority of most important ancestor
+        // weight based on the priority the leaf inherits from its closest
+        // prioritized ancestor
 
         let mut prio_pairs = vec![];
         let mut total_prio = 0;
         for &leaf in &leaves {
             let prio = self
-                .lineage(leaf)
+                .effective_tags(leaf)
                 .iter()
-                .filter_map(|&p| self.node_priority(p))
+                .filter_map(|tag| tag.strip_prefix("prio=")?.parse::<usize>().ok())
                 .max()
                 .unwrap_or(1);
             total_prio += prio;
@@ -349,22 +409,6 @@ impl Screen {
         self.zoom_select(choice);
     }
 
-    fn node_priority(&self, node_id: NodeID) -> Option<usize> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"#prio=(\d+)").unwrap();
-        }
-        self.with_node(node_id, |n| n.content.clone())
-            .and_then(|c| {
-                if RE.is_match(&*c) {
-                    RE.captures_iter(&*c)
-                        .nth(0)
-                        .and_then(|n| n.get(1).unwrap().as_str().parse::<usize>().ok())
-                } else {
-                    None
-                }
-            })
-    }
-
     fn single_key_prompt(&mut self, prompt: &str) -> io::Result<Key> {
         trace!("prompt({})", prompt);
         if self.is_test {
@@ -409,11 +453,234 @@ impl Screen {
         res
     }
 
-    fn enter_cmd(&mut self) {
-        trace!("enter_cmd()");
-        if let Ok(Some(cmd)) = self.prompt("cmd: ") {
-            debug!("received command {:?}", cmd);
+    // like prompt, but stays in raw mode so that we can offer tab
+    // completion, and shows an error from a previous attempt
+    fn cmd_prompt(
+        &mut self,
+        mut line: String,
+        error: Option<String>,
+    ) -> io::Result<Option<String>> {
+        trace!("cmd_prompt({}, {:?})", line, error);
+        if self.is_test {
+            return Err(Error::other("can't prompt in test"));
+        }
+
+        let prompt = if let Some(e) = error {
+            format!("{} │ cmd: ", e)
+        } else {
+            "cmd: ".to_owned()
+        };
+
+        // the candidates and current index while cycling with tab
+        let mut completions: Option<(Vec<String>, usize)> = None;
+
+        let stdin: Box<dyn Read> = Box::new(stdin());
+        let mut keys = stdin.keys();
+        loop {
+            print!(
+                "{}{}{}{}{}{}",
+                cursor::Goto(0, self.dims.1),
+                style::Invert,
+                clear::AfterCursor,
+                prompt,
+                line,
+                cursor::Show
+            );
+            self.flush();
+
+            let key = match keys.next() {
+                Some(key) => key?,
+                None => return Ok(None),
+            };
+            match key {
+                Key::Char('\n') => break,
+                Key::Char('\t') => {
+                    let (candidates, idx) = completions
+                        .take()
+                        .unwrap_or_else(|| (cmd::complete(&line, &self.tag_db.tags()), 0));
+                    if !candidates.is_empty() {
+                        line = candidates[idx % candidates.len()].clone();
+                        completions = Some((candidates, idx + 1));
+                    }
+                    continue;
+                }
+                Key::Char(c) => line.push(c),
+                Key::Backspace => {
+                    line.pop();
+                }
+                Key::Esc | Key::Ctrl('c') => {
+                    print!("{}{}", style::Reset, cursor::Hide);
+                    return Ok(None);
+                }
+                _ => {}
+            }
+            completions = None;
+        }
+        debug!("read cmd prompt: {:?}", line);
+        print!("{}{}", style::Reset, cursor::Hide);
+        Ok(Some(line))
+    }
+
+    // return of false signals to the caller that we are done in this view
+    fn enter_cmd(&mut self, mut line: String) -> bool {
+        trace!("enter_cmd({})", line);
+        let mut error = None;
+        while let Ok(Some(cmd_line)) = self.cmd_prompt(line, error.take()) {
+            debug!("received command {:?}", cmd_line);
+            match cmd::parse(&cmd_line).and_then(|cmd| self.run_cmd(cmd)) {
+                Ok(keep_going) => return keep_going,
+                Err(e) => {
+                    warn!("command failed: {}", e);
+                    line = cmd_line;
+                    error = Some(e);
+                }
+            }
+        }
+        true
+    }
+
+    fn run_cmd(&mut self, cmd: Cmd) -> Result<bool, String> {
+        trace!("run_cmd({:?})", cmd);
+        match cmd {
+            Cmd::Action(action) => return Ok(self.handle_action(action)),
+            Cmd::TagAdd(tag) => {
+                let selected_id = self.selected.ok_or("no node selected")?;
+                self.add_tag(selected_id, &tag);
+            }
+            Cmd::TagRemove(tag) => {
+                let selected_id = self.selected.ok_or("no node selected")?;
+                self.remove_tag(selected_id, &tag);
+            }
+            Cmd::TagRename(from, to, merge) => {
+                let node_ids = self.rename_tag_preview(&from, &to, merge)?;
+                let verb = if merge { "merge" } else { "rename" };
+                let prompt = format!(
+                    "{} #{} into #{} on {} nodes? [y/n] ",
+                    verb,
+                    from,
+                    to,
+                    node_ids.len()
+                );
+                if let Ok(Key::Char('y')) = self.single_key_prompt(&prompt) {
+                    let changed = self.rename_tag(&from, &to, merge)?;
+                    info!("{}d #{} into #{} on {} nodes", verb, from, to, changed);
+                }
+            }
+            Cmd::Sort(key, desc) => {
+                let node_id = self.selected.unwrap_or(self.drawing_root);
+                self.sort_children(node_id, &key, desc);
+            }
+            Cmd::Export(format, path) => {
+                let node_id = self.selected.unwrap_or(self.drawing_root);
+                let data = export(self, node_id, &format)?;
+                File::create(&path)
+                    .and_then(|mut f| f.write_all(data.as_bytes()))
+                    .map_err(|e| format!("can't write {}: {}", path, e))?;
+                info!("exported {} as {} to {}", node_id, format, path);
+            }
+            Cmd::Import(format, path) => {
+                let mut text = String::new();
+                File::open(&path)
+                    .and_then(|mut f| f.read_to_string(&mut text))
+                    .map_err(|e| format!("can't read {}: {}", path, e))?;
+                let outlines = import(&text, &format)?;
+                let parent_id = self.selected.unwrap_or(self.drawing_root);
+                let roots = self.graft(parent_id, outlines).unwrap();
+                info!("imported {} nodes from {}", roots.len(), path);
+            }
+            Cmd::Goto(node_id) => {
+                if !self.exists(node_id) {
+                    return Err(format!("no node with id {}", node_id));
+                }
+                self.zoom_select(node_id);
+            }
+            Cmd::Set(key, value) => match &*key {
+                "autosave" => {
+                    let every = value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&every| every > 0)
+                        .ok_or_else(|| format!("invalid autosave interval: {}", value))?;
+                    self.autosave_every = every;
+                }
+                "undo" => {
+                    let limit = value
+                        .parse::<usize>()
+                        .map_err(|_| format!("invalid undo history size: {}", value))?;
+                    self.undo.set_limit(limit);
+                }
+                "trash_days" => {
+                    self.trash_days = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid number of days: {}", value))?;
+                }
+                _ => return Err(format!("unknown setting: {}", key)),
+            },
+            Cmd::EmptyTrash(None) => self.trash.clear(),
+            Cmd::EmptyTrash(Some(age)) => {
+                let cutoff = dateparse(&age).map_err(|e| e.to_string())?;
+                self.purge_trash(cutoff);
+            }
+            Cmd::Log(value, at) => {
+                let selected_id = self.selected.ok_or("no node selected")?;
+                let time = match at {
+                    Some(at) => dateparse(&at).map_err(|e| e.to_string())?,
+                    None => now().as_secs(),
+                };
+                self.record_measurement(selected_id, time, value)?;
+            }
         }
+        Ok(true)
+    }
+
+    fn add_tag(&mut self, node_id: NodeID, tag: &str) {
+        let tag = format!("#{}", tag);
+        let before = self.snapshot(&[node_id]);
+        let content = self.with_node_mut(node_id, |n| {
+            if !n.content.split_whitespace().any(|word| word == tag) {
+                if !n.content.is_empty() {
+                    n.content.push(' ');
+                }
+                n.content.push_str(&tag);
+            }
+            n.content.clone()
+        });
+        if let Some(content) = content {
+            self.grapheme_cache.remove(&node_id);
+            self.tag_db.reindex(node_id, content);
+            self.record_edit(before);
+        }
+    }
+
+    fn remove_tag(&mut self, node_id: NodeID, tag: &str) {
+        let tag = format!("#{}", tag);
+        let tag_kv = format!("{}=", tag);
+        let before = self.snapshot(&[node_id]);
+        let content = self.with_node_mut(node_id, |n| {
+            n.content = n
+                .content
+                .split_whitespace()
+                .filter(|&word| word != tag && !word.starts_with(&tag_kv))
+                .collect::<Vec<_>>()
+                .join(" ");
+            n.content.clone()
+        });
+        if let Some(content) = content {
+            self.grapheme_cache.remove(&node_id);
+            self.tag_db.reindex(node_id, content);
+            self.record_edit(before);
+        }
+    }
+
+    fn sort_children(&mut self, node_id: NodeID, key: &SortKey, desc: bool) {
+        let mut children = match self.with_node(node_id, |n| n.children.clone()) {
+            Some(children) => children,
+            None => return,
+        };
+        key.sort(&mut children, desc, &self.nodes, &self.tag_db);
+        let before = self.snapshot(&[node_id]);
+        self.with_node_mut_no_meta(node_id, |n| n.children = children.clone());
+        self.record_edit(before);
     }
 
     fn search_forward(&mut self) {
@@ -633,8 +900,10 @@ impl Screen {
         let _ = remove_file(&path);
 
         // set node's saved text
+        let before = self.snapshot(&[node_id]);
         self.with_node_mut(node_id, |n| n.free_text = Some(new_text.clone()))
             .unwrap();
+        self.record_edit(before);
 
         // restore raw mode
         self.start_raw_mode();
@@ -704,15 +973,20 @@ impl Screen {
     }
 
     fn drawable_subtree_widths(&mut self, node_id: NodeID, depth: usize) -> Option<Vec<u16>> {
-        let raw_node_opt = self.with_node(node_id, |n| n.clone());
+        let raw_node_opt = self.drawable_node(node_id);
         if let Some(raw_node) = raw_node_opt {
             let node = self.format_node(&raw_node);
-            let width = 1 + (3 * depth as u16) + node.content.len() as u16;
+            let mut lines = node.content.split('\n');
+            let width = 1 + (3 * depth as u16) + lines.next().unwrap().chars().count() as u16;
             let mut ret = vec![width];
-            let hide_stricken = self.with_node(node_id, |n| n.hide_stricken).unwrap();
+            // the rest of a chart's rows, drawn below it
+            for line in lines {
+                ret.push(3 + (3 * depth as u16) + line.chars().count() as u16);
+            }
+            let hide_stricken = raw_node.hide_stricken;
             if !node.collapsed {
                 for &child in &node.children {
-                    let stricken = self.with_node(child, |c| c.stricken).unwrap();
+                    let stricken = self.drawable_node(child).unwrap().stricken;
                     if !(hide_stricken && stricken) {
                         // ASSUMES node.children are all valid
                         let mut child_widths =
@@ -740,6 +1014,7 @@ impl Screen {
         lazy_static! {
             static ref RE_DATE: Regex = Regex::new(r"\[(\S+)\]").unwrap();
         }
+        self.undo.seal();
         if let Some(selected_id) = self.selected {
             // nuke node if it's empty and has no children
             let deletable = self
@@ -753,44 +1028,68 @@ impl Screen {
                 return None;
             }
 
+            let before = self.snapshot(&[selected_id]);
             self.with_node_mut_no_meta(selected_id, |n| {
                 // if parseable date, change date
                 if let Some(date) = re_matches::<String>(&RE_DATE, &*n.content).get(0) {
-                    if let Some(date) = dateparse(date.clone()) {
+                    let finished = n.meta.finish_time.is_some();
+                    let parsed = if finished {
+                        dateparse(date)
+                    } else {
+                        dateparse_due(date)
+                    };
+                    if let Ok(date) = parsed {
                         n.content = RE_DATE.replace(&*n.content, "").trim_end().to_owned();
-                        if n.meta.finish_time.is_some() {
+                        if finished {
                             n.meta.finish_time = Some(date);
                         } else {
-                            let now_in_s = now().as_secs();
-                            let future_date = now_in_s + (now_in_s - date);
-                            n.meta.due = Some(future_date);
+                            n.meta.due = Some(date);
                         }
                     }
                 }
+                if let Err(e) = n.take_due_tag() {
+                    warn!("{}", e);
+                }
             });
+            if let Some(content) = self.with_node(selected_id, |n| n.content.clone()) {
+                if content != before[0].content {
+                    self.grapheme_cache.remove(&selected_id);
+                    self.tag_db.reindex(selected_id, content);
+                }
+            }
+            self.record_edit(before);
         }
         self.selected.take()
     }
 
+    // the rows the calendar takes up below the header, which
+    // push the map down while it's shown
+    fn calendar_rows(&self) -> u16 {
+        if self.show_calendar && self.dims.0 >= 4 && self.dims.1 >= CALENDAR_ROWS + 2 {
+            CALENDAR_ROWS
+        } else {
+            0
+        }
+    }
+
     fn internal_to_screen_xy(&self, coords: Coords) -> Option<Coords> {
         // + 2 compensates for header
-        if coords.1 < self.view_y + 2 || coords.1 > self.view_y + self.dims.1 {
+        let top = self.calendar_rows();
+        if coords.1 < self.view_y + 2 || coords.1 - self.view_y > self.dims.1 - top {
             // coords are above or below screen
             None
         } else {
-            Some((coords.0, coords.1 - self.view_y))
+            Some((coords.0, coords.1 - self.view_y + top))
         }
     }
 
     fn screen_to_internal_xy(&self, coords: Coords) -> Coords {
-        (
-            coords.0,
-            min(coords.1, std::u16::MAX - self.view_y) + self.view_y,
-        )
+        let y = coords.1.saturating_sub(self.calendar_rows());
+        (coords.0, min(y, std::u16::MAX - self.view_y) + self.view_y)
     }
 
-    fn coords_are_visible(&self, (_, y): Coords) -> bool {
-        visible(self.view_y + 1, self.dims.1, y)
+    fn coords_are_visible(&self, coords: Coords) -> bool {
+        self.internal_to_screen_xy(coords).is_some()
     }
 
     fn node_is_visible(&self, node: NodeID) -> Option<bool> {
@@ -806,6 +1105,10 @@ impl Screen {
         if self.dragging_from.is_none() {
             self.unselect();
             if let Some(&node_id) = self.lookup(coords) {
+                if let Some(&target) = self.ephemeral_targets.get(&node_id) {
+                    self.zoom_select(target);
+                    return self.selected;
+                }
                 return self
                     .with_node_mut_no_meta(node_id, |node| {
                         trace!("selected node {} at {:?}", node_id, coords);
@@ -833,14 +1136,18 @@ impl Screen {
     fn toggle_stricken(&mut self) {
         trace!("toggle_stricken()");
         if let Some(selected_id) = self.selected {
+            let before = self.snapshot(&[selected_id]);
             self.with_node_mut(selected_id, |node| node.toggle_stricken());
+            self.record_edit(before);
         }
     }
 
     fn toggle_hide_stricken(&mut self) {
         trace!("toggle_hide_stricken()");
         if let Some(selected_id) = self.selected {
+            let before = self.snapshot(&[selected_id]);
             self.with_node_mut(selected_id, |node| node.toggle_hide_stricken());
+            self.record_edit(before);
         }
     }
 
@@ -857,8 +1164,6 @@ impl Screen {
             for child_id in &node.children {
                 self.delete_recursive(*child_id);
             }
-
-            self.undo_nodes.insert(node_id, node);
         }
     }
 
@@ -867,12 +1172,20 @@ impl Screen {
         if let Some(selected_id) = self.selected.take() {
             let (_, height) = self.drawable_subtree_dims(selected_id).unwrap();
             let coords = self.drawn_at.remove(&selected_id);
+            let (nodes, arrows) = self.subtree(selected_id);
+            let mut idx = 0;
             // remove ref from parent
-            if let Some(parent_id) = self.parent(selected_id) {
-                trace!("deleting node {} from parent {}", selected_id, parent_id);
-                self.with_node_mut_no_meta(parent_id, |p| p.children.retain(|c| c != &selected_id))
-                    .unwrap();
-            }
+            let parent_id = self.parent(selected_id).unwrap_or(0);
+            trace!("deleting node {} from parent {}", selected_id, parent_id);
+            self.with_node_mut_no_meta(parent_id, |p| {
+                idx = p
+                    .children
+                    .iter()
+                    .position(|&c| c == selected_id)
+                    .unwrap_or(0);
+                p.children.retain(|c| c != &selected_id)
+            })
+            .unwrap();
             // remove children
             self.delete_recursive(selected_id);
             if let Some((x, y)) = coords {
@@ -880,33 +1193,423 @@ impl Screen {
                     self.click_select((x, y + height));
                 }
             }
-            self.undo_stack.push(selected_id);
+            self.trash_subtree(parent_id, idx, nodes.clone(), arrows.clone());
+            if !self.undo.forget_created(selected_id) {
+                self.undo.record(Op::Remove {
+                    parent: parent_id,
+                    idx,
+                    nodes,
+                    arrows,
+                });
+            }
         }
     }
 
-    fn undo_delete(&mut self) {
-        if let Some(node_id) = self.undo_stack.pop() {
-            self.recursive_restore(node_id).unwrap();
-            self.select_node(node_id);
+    // a copy of a subtree in pre-order, and every arrow touching it
+    fn subtree(&self, node_id: NodeID) -> (Vec<Node>, Vec<(NodeID, NodeID)>) {
+        let nodes = self.recursive_child_filter_map(node_id, &mut |n: &Node| {
+            let mut n = n.clone();
+            n.selected = false;
+            Some(n)
+        });
+        let arrows = self
+            .arrows
+            .iter()
+            .filter(|&&(from, to)| nodes.iter().any(|n| n.id == from || n.id == to))
+            .cloned()
+            .collect();
+        (nodes, arrows)
+    }
+
+    // nodes are stored unselected, so that selection never counts as an edit
+    fn snapshot(&self, node_ids: &[NodeID]) -> Vec<Node> {
+        let mut ret: Vec<Node> = vec![];
+        for node_id in node_ids {
+            if ret.iter().any(|n| n.id == *node_id) {
+                continue;
+            }
+            if let Some(mut node) = self.with_node(*node_id, |n| n.clone()) {
+                node.selected = false;
+                ret.push(node);
+            }
+        }
+        ret
+    }
+
+    // record the change from a snapshot taken before modifying these nodes
+    fn record_edit(&mut self, before: Vec<Node>) {
+        let ids: Vec<NodeID> = before.iter().map(|n| n.id).collect();
+        let after = self.snapshot(&ids);
+        if before != after {
+            self.undo.record(Op::Edit { before, after });
         }
     }
 
-    fn recursive_restore(&mut self, node_id: NodeID) -> Result<(), ()> {
-        if let Some(node) = self.undo_nodes.remove(&node_id) {
-            self.with_node_mut_no_meta(node.parent_id, |p| {
-                if !p.children.contains(&node.id) {
-                    p.children.push(node.id);
+    fn record_typing(&mut self, node_id: NodeID, before: Node) {
+        if let Some(after) = self.snapshot(&[node_id]).pop() {
+            self.undo.record_typing(before, after);
+        }
+    }
+
+    fn record_insert(&mut self, node_id: NodeID) {
+        if let Some(parent_id) = self.parent(node_id) {
+            let idx = self
+                .with_node(parent_id, |p| p.children.iter().position(|&c| c == node_id))
+                .unwrap()
+                .unwrap_or(0);
+            let nodes = self.snapshot(&[node_id]);
+            self.undo.record(Op::Insert {
+                parent: parent_id,
+                idx,
+                nodes,
+                arrows: vec![],
+            });
+        }
+    }
+
+    fn undo(&mut self) {
+        // drop the selection without the usual cleanup on unselect, as
+        // that may itself create history, and leave an empty node to undo.
+        self.undo.seal();
+        if let Some(selected_id) = self.selected.take() {
+            self.with_node_mut_no_meta(selected_id, |n| n.selected = false);
+        }
+        if let Some(op) = self.undo.undo() {
+            self.apply_op(op);
+        }
+    }
+
+    fn redo(&mut self) {
+        self.unselect();
+        if let Some(op) = self.undo.redo() {
+            self.apply_op(op);
+        }
+    }
+
+    fn apply_op(&mut self, op: Op) {
+        trace!("apply_op({:?})", op);
+        let focus = op.focus();
+        match op {
+            Op::Edit { after, .. } => {
+                for node in after {
+                    self.grapheme_cache.remove(&node.id);
+                    self.tag_db.reindex(node.id, node.content.clone());
+                    self.nodes.insert(node.id, node);
                 }
-            })
-            .unwrap();
-            let children = node.children.clone();
-            self.nodes.insert(node_id, node);
-            for &child in &children {
-                self.recursive_restore(child)?;
             }
-            Ok(())
+            Op::Insert {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            } => {
+                if let Some(root) = nodes.first().map(|n| n.id) {
+                    self.with_node_mut_no_meta(parent, |p| {
+                        let idx = min(idx, p.children.len());
+                        p.children.insert(idx, root);
+                    });
+                    self.trash.retain(|e| e.root() != Some(root));
+                }
+                for node in nodes {
+                    self.grapheme_cache.remove(&node.id);
+                    self.tag_db.reindex(node.id, node.content.clone());
+                    self.nodes.insert(node.id, node);
+                }
+                self.arrows.extend(arrows);
+            }
+            Op::Remove {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            } => {
+                if let Some(root) = nodes.first().map(|n| n.id) {
+                    self.with_node_mut_no_meta(parent, |p| p.children.retain(|&c| c != root));
+                    self.delete_recursive(root);
+                }
+                self.trash_subtree(parent, idx, nodes, arrows);
+            }
+            Op::AddArrow(from, to) => {
+                if self.exists(from) && self.exists(to) && !self.arrows.contains(&(from, to)) {
+                    self.arrows.push((from, to));
+                }
+            }
+            Op::RemoveArrow(from, to) => self.arrows.retain(|&arrow| arrow != (from, to)),
+            Op::Group(ops) => {
+                for op in ops {
+                    self.apply_op(op);
+                }
+            }
+        }
+
+        // the view, a pending cut or arrow may refer to removed nodes
+        while !self.exists(self.drawing_root) {
+            self.pop_focus();
+        }
+        if self.cut.is_some_and(|c| !self.exists(c)) {
+            self.cut = None;
+        }
+        if self.drawing_arrow.is_some_and(|a| !self.exists(a)) {
+            self.drawing_arrow = None;
+        }
+
+        if let Some(node_id) = focus {
+            let root = self.drawing_root;
+            if node_id != root && self.exists(node_id) && self.is_parent(root, node_id) {
+                self.select_node(node_id);
+            }
+        }
+    }
+
+    // anything removed from the tree lands in the trash, which is saved
+    fn trash_subtree(
+        &mut self,
+        parent_id: NodeID,
+        idx: usize,
+        nodes: Vec<Node>,
+        arrows: Vec<(NodeID, NodeID)>,
+    ) {
+        let entry = TrashEntry {
+            parent_id,
+            idx,
+            deleted_at: now().as_secs(),
+            nodes,
+            arrows,
+        };
+        if !entry.is_empty() {
+            self.trash.push(entry);
+        }
+    }
+
+    // put a trashed subtree back under its old parent, or under
+    // home if that is gone too. this is undoable like any insert.
+    fn restore_from_trash(&mut self, trash_idx: usize) {
+        trace!("restore_from_trash({})", trash_idx);
+        let mut entry = self.trash.remove(trash_idx);
+        let (parent, idx) = if self.exists(entry.parent_id) {
+            (entry.parent_id, entry.idx)
         } else {
-            Err(())
+            (0, self.with_node(0, |n| n.children.len()).unwrap())
+        };
+        if let Some(root) = entry.nodes.first_mut() {
+            root.parent_id = parent;
+        }
+        let nodes = entry.nodes;
+        let arrows = entry
+            .arrows
+            .into_iter()
+            .filter(|&(from, to)| {
+                [from, to]
+                    .iter()
+                    .all(|&id| self.exists(id) || nodes.iter().any(|n| n.id == id))
+            })
+            .collect();
+        let op = Op::Insert {
+            parent,
+            idx,
+            nodes,
+            arrows,
+        };
+        self.unselect();
+        self.undo.record(op.clone());
+        self.apply_op(op);
+    }
+
+    // forget trash deleted before the cutoff, in seconds since the epoch
+    fn purge_trash(&mut self, cutoff: u64) {
+        let before = self.trash.len();
+        self.trash.retain(|e| e.deleted_at > cutoff);
+        info!("purged {} items from the trash", before - self.trash.len());
+    }
+
+    pub fn expire_trash(&mut self) {
+        if self.trash_days > 0 {
+            let cutoff = now()
+                .as_secs()
+                .saturating_sub(self.trash_days * 60 * 60 * 24);
+            self.purge_trash(cutoff);
+        }
+    }
+
+    fn describe_trash_entry(&self, entry: &TrashEntry, now: u64) -> String {
+        let root = &entry.nodes[0];
+        let mut ret = format!("{} │ {}", trash::ago(entry.deleted_at, now), root.content);
+        if entry.nodes.len() > 1 {
+            write!(&mut ret, " (+{} below)", entry.nodes.len() - 1).unwrap();
+        }
+        match self.with_node(entry.parent_id, |p| p.content.clone()) {
+            Some(parent) => write!(&mut ret, " │ from {}", parent).unwrap(),
+            None => ret.push_str(" │ from a deleted node"),
+        }
+        ret
+    }
+
+    // a full-screen list of the trash, newest first
+    fn browse_trash(&mut self) {
+        trace!("browse_trash()");
+        let mut cursor = 0;
+        loop {
+            let now = now().as_secs();
+            let lines: Vec<String> = self
+                .trash
+                .iter()
+                .rev()
+                .map(|e| self.describe_trash_entry(e, now))
+                .collect();
+            cursor = min(cursor, lines.len().saturating_sub(1));
+
+            // the last line is taken by the prompt
+            let height = max(self.dims.1, 2) as usize - 1;
+            let start = cursor / height * height;
+            print!("{}{}", cursor::Goto(1, 1), clear::All);
+            if lines.is_empty() {
+                print!("the trash is empty");
+            }
+            for (i, line) in lines.iter().enumerate().skip(start).take(height) {
+                let line: String = line.chars().take(self.dims.0 as usize).collect();
+                print!("{}", cursor::Goto(1, (i - start) as u16 + 1));
+                if i == cursor {
+                    print!("{}{}{}", style::Invert, line, style::Reset);
+                } else {
+                    print!("{}", line);
+                }
+            }
+
+            let prompt = format!(
+                "trash │ enter: restore │ d: delete forever │ e: empty older than {} days │ esc: back",
+                self.trash_days
+            );
+            match self.single_key_prompt(&prompt) {
+                Ok(Key::Up) => cursor = cursor.saturating_sub(1),
+                Ok(Key::Down) => cursor += 1,
+                Ok(Key::PageUp) => cursor = cursor.saturating_sub(height),
+                Ok(Key::PageDown) => cursor += height,
+                Ok(Key::Char('\n')) if !lines.is_empty() => {
+                    let trash_idx = self.trash.len() - 1 - cursor;
+                    self.restore_from_trash(trash_idx);
+                    return;
+                }
+                Ok(Key::Char('d')) if !lines.is_empty() => {
+                    let trash_idx = self.trash.len() - 1 - cursor;
+                    self.trash.remove(trash_idx);
+                }
+                Ok(Key::Char('e')) => self.expire_trash(),
+                Ok(Key::Esc) | Ok(Key::Char('q')) | Err(_) => return,
+                _ => {}
+            }
+        }
+    }
+
+    // a panel on the right listing every tag with its counts. picking a
+    // tag lists the nodes carrying it, and picking one of those jumps there.
+    fn browse_tags(&mut self) {
+        trace!("browse_tags()");
+        let mut by_name = false;
+        let mut cursor = 0;
+        // the tag being shown, with the cursor to return to in the tag list
+        let mut shown: Option<(String, usize)> = None;
+        loop {
+            let (title, lines, node_ids) = match shown {
+                None => {
+                    let counts = self.tag_db.counts(&self.nodes, by_name);
+                    let lines: Vec<String> = counts
+                        .iter()
+                        .map(|c| format!("{:>4} {:>4} {:>4} #{}", c.total, c.open, c.done, c.tag))
+                        .collect();
+                    ("  all open done".to_owned(), lines, vec![])
+                }
+                Some((ref tag, _)) => {
+                    let node_ids: Vec<NodeID> = self
+                        .tag_db
+                        .tag_to_nodes(tag)
+                        .into_iter()
+                        .filter(|id| self.nodes.contains_key(id))
+                        .collect();
+                    let lines = node_ids
+                        .iter()
+                        .map(|id| {
+                            let node = &self.nodes[id];
+                            let mark = if node.stricken { '☑' } else { '☐' };
+                            format!("{} {}", mark, node.content)
+                        })
+                        .collect();
+                    (format!("#{}", tag), lines, node_ids)
+                }
+            };
+            cursor = min(cursor, lines.len().saturating_sub(1));
+            self.draw_panel(&title, &lines, cursor);
+
+            // the header and the prompt take a line each, as does the title
+            let height = max(self.dims.1, 4) as usize - 3;
+            let prompt = if shown.is_some() {
+                "tags │ enter: jump to node │ esc: back to tags".to_owned()
+            } else {
+                let order = if by_name { "count" } else { "name" };
+                format!(
+                    "tags │ enter: show nodes │ s: sort by {} │ esc: close",
+                    order
+                )
+            };
+            match self.single_key_prompt(&prompt) {
+                Ok(Key::Up) => cursor = cursor.saturating_sub(1),
+                Ok(Key::Down) => cursor += 1,
+                Ok(Key::PageUp) => cursor = cursor.saturating_sub(height),
+                Ok(Key::PageDown) => cursor += height,
+                Ok(Key::Char('\n')) if !lines.is_empty() => match shown {
+                    None => {
+                        let counts = self.tag_db.counts(&self.nodes, by_name);
+                        shown = Some((counts[cursor].tag.clone(), cursor));
+                        cursor = 0;
+                    }
+                    Some(_) => {
+                        self.zoom_select(node_ids[cursor]);
+                        return;
+                    }
+                },
+                Ok(Key::Char('s')) if shown.is_none() => by_name = !by_name,
+                Ok(Key::Esc) | Ok(Key::Left) | Ok(Key::Backspace) if shown.is_some() => {
+                    cursor = shown.take().unwrap().1;
+                }
+                Ok(Key::Esc) | Ok(Key::Char('q')) | Err(_) => return,
+                Ok(key) if self.config.map(Event::Key(key)) == Some(Action::Tags) => return,
+                _ => {}
+            }
+        }
+    }
+
+    // draws a list over the right third of the screen, below the header
+    fn draw_panel(&self, title: &str, lines: &[String], cursor: usize) {
+        if self.dims.0 < 2 {
+            return;
+        }
+        let width = min(self.dims.0, max(32, self.dims.0 / 3));
+        let x = self.dims.0 - width + 1;
+        let height = max(self.dims.1, 4) as usize - 3;
+        let start = cursor / height * height;
+        let fit = |text: &str| {
+            let mut text: String = text.chars().take(width as usize - 1).collect();
+            let len = text.chars().count();
+            text.push_str(&" ".repeat(width as usize - 1 - len));
+            text
+        };
+        print!(
+            "{}│{}{}{}",
+            cursor::Goto(x, 2),
+            style::Invert,
+            fit(title),
+            style::Reset
+        );
+        for row in 0..height {
+            let i = start + row;
+            print!("{}│", cursor::Goto(x, row as u16 + 3));
+            match lines.get(i) {
+                Some(line) if i == cursor => {
+                    print!("{}{}{}", style::Invert, fit(line), style::Reset)
+                }
+                Some(line) => print!("{}", fit(line)),
+                None if lines.is_empty() && row == 0 => print!("{}", fit("nothing is tagged")),
+                None => print!("{}", fit("")),
+            }
         }
     }
 
@@ -917,11 +1620,14 @@ impl Screen {
 
     fn toggle_auto_arrange(&mut self) {
         let root = self.drawing_root;
+        let before = self.snapshot(&[root]);
         self.with_node_mut_no_meta(root, |n| n.auto_arrange = !n.auto_arrange)
-            .unwrap()
+            .unwrap();
+        self.record_edit(before);
     }
 
     pub fn run(&mut self) {
+        self.expire_trash();
         self.start_raw_mode();
         self.dims = terminal_size().unwrap();
         self.draw();
@@ -961,7 +1667,9 @@ impl Screen {
     fn toggle_collapsed(&mut self) {
         trace!("toggle_collapsed()");
         if let Some(selected_id) = self.selected {
+            let before = self.snapshot(&[selected_id]);
             self.with_node_mut_no_meta(selected_id, |node| node.toggle_collapsed());
+            self.record_edit(before);
         }
     }
 
@@ -1001,6 +1709,10 @@ impl Screen {
             }
             let selected_id = selected_id;
 
+            // finish editing the parent before it gets the child, so
+            // that undoing an edit made on unselecting it doesn't bring
+            // back an undone child
+            self.unselect();
             let node_id = self.new_node();
             self.with_node_mut_no_meta(node_id, |node| node.parent_id = selected_id);
             let added = self.with_node_mut_no_meta(selected_id, |selected| {
@@ -1008,6 +1720,7 @@ impl Screen {
             });
             if added.is_some() {
                 self.select_node(node_id);
+                self.record_insert(node_id);
             } else {
                 self.delete_recursive(node_id);
             }
@@ -1053,6 +1766,7 @@ impl Screen {
                 });
                 if added.is_some() {
                     self.select_node(node_id);
+                    self.record_insert(node_id);
                 } else {
                     self.delete_recursive(node_id);
                 }
@@ -1102,11 +1816,13 @@ impl Screen {
         });
         self.with_node_mut_no_meta(root, |root| root.children.push(node_id));
         self.select_node(node_id);
+        self.record_insert(node_id);
     }
 
     fn backspace(&mut self) {
         trace!("backspace");
         if let Some(selected_id) = self.selected {
+            let before = self.snapshot(&[selected_id]);
             if let Some(content) = self.with_node_mut(selected_id, |node| {
                 let content = node.content.clone();
                 let chars = content.chars();
@@ -1117,6 +1833,7 @@ impl Screen {
             }) {
                 self.grapheme_cache.remove(&selected_id);
                 self.tag_db.reindex(selected_id, content);
+                self.record_typing(selected_id, before[0].clone());
             }
         }
     }
@@ -1124,14 +1841,90 @@ impl Screen {
     fn append(&mut self, c: char) {
         trace!("append({})", c);
         if let Some(selected_id) = self.selected {
+            let before = self.snapshot(&[selected_id]);
             if let Some(content) = self.with_node_mut(selected_id, |node| {
                 node.content.push(c);
                 node.content.clone()
             }) {
                 self.grapheme_cache.remove(&selected_id);
                 self.tag_db.reindex(selected_id, content);
-            }
-        }
+                self.record_typing(selected_id, before[0].clone());
+            }
+        }
+    }
+
+    // the tag being typed at the end of a node, without its `#`
+    fn partial_tag(&self, node_id: NodeID) -> Option<String> {
+        let content = self.with_node(node_id, |n| n.content.clone())?;
+        if content.ends_with(char::is_whitespace) {
+            return None;
+        }
+        let word = content.split_whitespace().last()?;
+        word.strip_prefix('#').map(|w| w.to_owned())
+    }
+
+    // tags in use elsewhere that a partial tag may become. after a
+    // `key=` these are the values that key has been given.
+    fn tag_candidates(&self, node_id: NodeID, partial: &str) -> Vec<String> {
+        // the node being typed into has already indexed the partial tag
+        let elsewhere = |ids: &[NodeID]| ids.iter().any(|&id| id != node_id);
+        let mut candidates: Vec<String> = match partial.find('=') {
+            Some(i) => self
+                .tag_db
+                .values(&partial[..i])
+                .into_iter()
+                .filter(|(_, ids)| elsewhere(ids))
+                .map(|(value, _)| format!("{}={}", &partial[..i], value))
+                .collect(),
+            None => self
+                .tag_db
+                .tags()
+                .into_iter()
+                .filter(|tag| !tag.contains('=') && elsewhere(&self.tag_db.tag_to_nodes(tag)))
+                .collect(),
+        };
+        candidates.retain(|c| c.starts_with(partial));
+        candidates.sort();
+        // nothing to offer for a tag that is already complete, even if
+        // longer ones start with it, so that Tab creates a child
+        if candidates.iter().any(|c| c == partial) {
+            candidates.clear();
+        }
+        candidates
+    }
+
+    // Tab after a partial tag replaces it with each of its candidates
+    // in turn, returning false if there is nothing to complete
+    fn complete_tag(&mut self) -> bool {
+        let selected_id = match self.selected {
+            Some(selected_id) => selected_id,
+            None => return false,
+        };
+        let partial = match self.partial_tag(selected_id) {
+            Some(partial) => partial,
+            None => return false,
+        };
+        let (candidates, idx) = self
+            .tag_completions
+            .take()
+            .unwrap_or_else(|| (self.tag_candidates(selected_id, &partial), 0));
+        if candidates.is_empty() {
+            return false;
+        }
+        let tag = candidates[idx % candidates.len()].clone();
+        let before = self.snapshot(&[selected_id]);
+        if let Some(content) = self.with_node_mut(selected_id, |node| {
+            let start = node.content.len() - partial.len();
+            node.content.truncate(start);
+            node.content.push_str(&tag);
+            node.content.clone()
+        }) {
+            self.grapheme_cache.remove(&selected_id);
+            self.tag_db.reindex(selected_id, content);
+            self.record_typing(selected_id, before[0].clone());
+        }
+        self.tag_completions = Some((candidates, idx + 1));
+        true
     }
 
     pub fn drawn_at(&self, node_id: NodeID) -> Option<&Coords> {
@@ -1226,6 +2019,12 @@ impl Screen {
             return;
         };
         if let Some(&new_parent) = self.lookup(to) {
+            // dropping onto a query match moves under the real node
+            let new_parent = self
+                .ephemeral_targets
+                .get(&new_parent)
+                .cloned()
+                .unwrap_or(new_parent);
             if !self.is_parent(selected_id, new_parent) {
                 self.reparent(selected_id, new_parent);
             } else {
@@ -1234,6 +2033,7 @@ impl Screen {
                 // than create a cycle, we move the subtree.
                 let ptr = self.anchor(selected_id).unwrap();
                 trace!("move selected 2");
+                let before = self.snapshot(&[ptr]);
                 self.with_node_mut_no_meta(ptr, |root| {
                     let (ox, oy) = root.rooted_coords;
                     let nx = max(ox as i16 + dx, 1) as u16;
@@ -1241,6 +2041,7 @@ impl Screen {
                     root.rooted_coords = (nx, ny);
                 })
                 .unwrap();
+                self.record_edit(before);
             }
         } else {
             // destination is not another node, so redraw selected at coords
@@ -1248,9 +2049,10 @@ impl Screen {
             // 2. add to drawing_root's children
             // 3. update rooted_coords
             let old_parent = self.parent(selected_id).unwrap();
+            let root = self.drawing_root;
+            let before = self.snapshot(&[selected_id, old_parent, root]);
             self.with_node_mut_no_meta(old_parent, |op| op.children.retain(|c| c != &selected_id))
                 .unwrap();
-            let root = self.drawing_root;
             self.with_node_mut_no_meta(root, |dr| dr.children.push(selected_id))
                 .unwrap();
             self.with_node_mut_no_meta(selected_id, |s| {
@@ -1258,6 +2060,7 @@ impl Screen {
                 s.parent_id = root;
             })
             .unwrap();
+            self.record_edit(before);
         }
         trace!("leaving move_selected");
     }
@@ -1414,6 +2217,7 @@ impl Screen {
                 // principle: don't modify things that are above the visible scope
                 return;
             }
+            let before = self.snapshot(&[selected_id, parent_id]);
             self.with_node_mut_no_meta(parent_id, |parent| {
                 let idx = parent
                     .children
@@ -1423,6 +2227,7 @@ impl Screen {
                 let to = max(idx, 1) - 1;
                 parent.children.swap(idx, to);
             });
+            self.record_edit(before);
         }
     }
 
@@ -1437,6 +2242,7 @@ impl Screen {
                 // principle: don't modify things that are above the visible scope
                 return;
             }
+            let before = self.snapshot(&[selected_id, parent_id]);
             self.with_node_mut_no_meta(parent_id, |parent| {
                 let idx = parent
                     .children
@@ -1449,12 +2255,13 @@ impl Screen {
                     parent.children.swap(idx, to);
                 }
             });
+            self.record_edit(before);
         }
     }
 
     fn select_up(&mut self) {
         let view_y = self.view_y;
-        let height = self.dims.1;
+        let height = self.dims.1 - self.calendar_rows();
         self.select_relative(|(l1, _), (l2, _)| {
             let is_up = l1.1 > l2.1;
             let (diff_x, diff_y) = distances(l1, l2);
@@ -1469,7 +2276,7 @@ impl Screen {
 
     fn select_down(&mut self) {
         let view_y = self.view_y;
-        let height = self.dims.1;
+        let height = self.dims.1 - self.calendar_rows();
         self.select_relative(|(l1, _), (l2, _)| {
             let is_down = l1.1 < l2.1;
             let (diff_x, diff_y) = distances(l1, l2);
@@ -1541,6 +2348,11 @@ impl Screen {
 
     fn select_node(&mut self, node_id: NodeID) {
         trace!("select_node({})", node_id);
+        // query matches lead to the real node
+        if let Some(&target) = self.ephemeral_targets.get(&node_id) {
+            self.zoom_select(target);
+            return;
+        }
         self.unselect();
         if node_id != 0 {
             // it's possible that unselecting above actually caused
@@ -1565,7 +2377,7 @@ impl Screen {
         }
         let old = self.selected;
         let new = self.try_select(coords);
-        if old.is_none() && self.dragging_from.is_none() {
+        if old.is_none() && new.is_none() && self.dragging_from.is_none() {
             self.create_anchor(coords);
         }
 
@@ -1649,6 +2461,247 @@ impl Screen {
         }
     }
 
+    // find a node by id, or by a path of node contents separated
+    // by slashes, optionally starting with the root: `home/work/today`
+    pub fn resolve(&self, spec: &str) -> Option<NodeID> {
+        if let Ok(node_id) = spec.parse::<NodeID>() {
+            return Some(node_id).filter(|&id| self.exists(id));
+        }
+        let mut parts: Vec<&str> = spec.split('/').filter(|p| !p.is_empty()).collect();
+        let root_content = self.with_node(0, |n| n.content.clone()).unwrap();
+        if parts.first() == Some(&root_content.as_str()) {
+            parts.remove(0);
+        }
+        let mut node_id = 0;
+        for part in parts {
+            let children = self.with_node(node_id, |n| n.children.clone()).unwrap();
+            // tags may be left out of the path
+            node_id = children.into_iter().find(|&c| {
+                self.with_node(c, |n| {
+                    let untagged: Vec<&str> = n
+                        .content
+                        .split_whitespace()
+                        .filter(|w| !w.starts_with('#'))
+                        .collect();
+                    n.content == part || untagged.join(" ") == part
+                })
+                .unwrap_or(false)
+            })?;
+        }
+        Some(node_id)
+    }
+
+    // the inverse of resolve
+    pub fn node_path(&self, node_id: NodeID) -> String {
+        let mut lineage = self.lineage(node_id);
+        lineage.dedup();
+        lineage
+            .into_iter()
+            .filter_map(|id| self.with_node(id, |n| n.content.clone()))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    // the following are for making changes without a terminal,
+    // so they neither select nor draw anything
+
+    pub fn add_node(&mut self, parent_id: NodeID, content: String) -> Option<NodeID> {
+        let mut outline = Outline::new(&content);
+        // a bad date stays in the text for the user to fix
+        let _ = outline.node.take_due_tag();
+        self.graft(parent_id, vec![outline]).map(|roots| roots[0])
+    }
+
+    // attaches subtrees below a parent, returning their new ids.
+    // they are undone together in one step.
+    pub fn graft(&mut self, parent_id: NodeID, outlines: Vec<Outline>) -> Option<Vec<NodeID>> {
+        if !self.exists(parent_id) {
+            return None;
+        }
+        let mut roots = vec![];
+        let mut ops = vec![];
+        for outline in outlines {
+            // in case the parent is ever drilled into, place the new
+            // subtree below its siblings instead of on top of them
+            let siblings = self.with_node(parent_id, |p| p.children.clone()).unwrap();
+            let mut coords = Node::default().rooted_coords;
+            for &sibling in &siblings {
+                let (_, height) = self.drawable_subtree_dims(sibling).unwrap();
+                let (x, y) = self.with_node(sibling, |n| n.rooted_coords).unwrap();
+                if y + height > coords.1 {
+                    coords = (x, y + height);
+                }
+            }
+
+            let node_id = self.insert_outline(parent_id, outline);
+            self.with_node_mut_no_meta(node_id, |n| n.rooted_coords = coords);
+            self.with_node_mut_no_meta(parent_id, |p| p.children.push(node_id));
+            let (nodes, _) = self.subtree(node_id);
+            ops.push(Op::Insert {
+                parent: parent_id,
+                idx: siblings.len(),
+                nodes,
+                arrows: vec![],
+            });
+            roots.push(node_id);
+        }
+        if ops.len() == 1 {
+            self.undo.record(ops.pop().unwrap());
+        } else if !ops.is_empty() {
+            self.undo.record(Op::Group(ops));
+        }
+        Some(roots)
+    }
+
+    fn insert_outline(&mut self, parent_id: NodeID, outline: Outline) -> NodeID {
+        let Outline { mut node, children } = outline;
+        let node_id = self.new_node_id();
+        node.id = node_id;
+        node.parent_id = parent_id;
+        node.children = vec![];
+        node.selected = false;
+        self.tag_db.reindex(node_id, node.content.clone());
+        self.nodes.insert(node_id, node);
+        for child in children {
+            let child_id = self.insert_outline(node_id, child);
+            self.with_node_mut_no_meta(node_id, |n| n.children.push(child_id));
+        }
+        node_id
+    }
+
+    // returns false if there is no such node, or it was already done
+    pub fn finish_node(&mut self, node_id: NodeID) -> bool {
+        if !self.with_node(node_id, |n| !n.stricken).unwrap_or(false) {
+            return false;
+        }
+        let before = self.snapshot(&[node_id]);
+        self.with_node_mut(node_id, |n| n.toggle_stricken());
+        self.record_edit(before);
+        true
+    }
+
+    // the name of the time series a node keeps, from its `#metric=` tag
+    pub fn metric_name(&self, node_id: NodeID) -> Option<String> {
+        self.tag_db
+            .node_tags(node_id)
+            .into_iter()
+            .find_map(|tag| tag.strip_prefix("metric=").map(|name| name.to_owned()))
+    }
+
+    // adds a value, taken at a time in seconds since the epoch, to the
+    // measurements of a node tagged `#metric=<name>`
+    pub fn record_measurement(
+        &mut self,
+        node_id: NodeID,
+        time: u64,
+        value: f64,
+    ) -> Result<(), String> {
+        if !value.is_finite() {
+            return Err(format!("not a number: {}", value));
+        }
+        if self.metric_name(node_id).is_none() {
+            return Err(format!("node {} has no #metric= tag", node_id));
+        }
+        let before = self.snapshot(&[node_id]);
+        self.with_node_mut(node_id, |n| n.meta.record(time, value));
+        self.record_edit(before);
+        Ok(())
+    }
+
+    // moves a subtree to the trash
+    pub fn remove_node(&mut self, node_id: NodeID) -> bool {
+        if node_id == 0 || !self.exists(node_id) {
+            return false;
+        }
+        let parent = self.parent(node_id).unwrap();
+        let idx = self
+            .with_node(parent, |p| p.children.iter().position(|&c| c == node_id))
+            .unwrap()
+            .unwrap_or(0);
+        let (nodes, arrows) = self.subtree(node_id);
+        let op = Op::Remove {
+            parent,
+            idx,
+            nodes,
+            arrows,
+        };
+        self.undo.record(op.clone());
+        self.apply_op(op);
+        if let Some(selected_id) = self.selected.take() {
+            self.with_node_mut_no_meta(selected_id, |n| n.selected = false);
+        }
+        true
+    }
+
+    // the nodes that renaming a tag would change, after checking that
+    // the rename makes sense. merging allows renaming onto a tag in use.
+    pub fn rename_tag_preview(
+        &self,
+        from: &str,
+        to: &str,
+        merge: bool,
+    ) -> Result<Vec<NodeID>, String> {
+        for tag in &[from, to] {
+            if tag.is_empty() || tag.contains(|c: char| c.is_whitespace() || c == '#' || c == '=') {
+                return Err(format!("invalid tag name: {:?}", tag));
+            }
+        }
+        if from == to || tag_suffix(to, from).is_some() {
+            return Err(format!("can't rename #{} to #{}", from, to));
+        }
+        let in_use = !self.tag_db.tag_to_nodes(to).is_empty()
+            || self.nodes.values().any(|n| n.meta.tags.contains_key(to));
+        if in_use && !merge {
+            return Err(format!("#{} is already in use, merge instead", to));
+        }
+        let mut node_ids: Vec<NodeID> = self
+            .nodes
+            .values()
+            .filter(|n| {
+                rename_tag_in(&n.content, from, to).is_some()
+                    || n.meta.tags.keys().any(|k| tag_suffix(k, from).is_some())
+            })
+            .map(|n| n.id)
+            .collect();
+        if node_ids.is_empty() {
+            return Err(format!("no node is tagged #{}", from));
+        }
+        node_ids.sort();
+        Ok(node_ids)
+    }
+
+    // rewrites `#from`, `#from=value`, tags nested below it,
+    // `#tagged=from`, `#sort=from` and `from` in `#q=` everywhere,
+    // returning how many nodes changed
+    pub fn rename_tag(&mut self, from: &str, to: &str, merge: bool) -> Result<usize, String> {
+        let node_ids = self.rename_tag_preview(from, to, merge)?;
+        let before = self.snapshot(&node_ids);
+        for &node_id in &node_ids {
+            let content = self.with_node_mut(node_id, |n| {
+                if let Some(content) = rename_tag_in(&n.content, from, to) {
+                    n.content = content;
+                }
+                let keys: Vec<String> = n.meta.tags.keys().cloned().collect();
+                for key in keys {
+                    if let Some(rest) = tag_suffix(&key, from) {
+                        let value = n.meta.tags.remove(&key).unwrap();
+                        n.meta
+                            .tags
+                            .entry(format!("{}{}", to, rest))
+                            .or_insert(value);
+                    }
+                }
+                n.content.clone()
+            });
+            if let Some(content) = content {
+                self.grapheme_cache.remove(&node_id);
+                self.tag_db.reindex(node_id, content);
+            }
+        }
+        self.record_edit(before);
+        Ok(node_ids.len())
+    }
+
     pub fn cleanup(&mut self) {
         trace!("cleanup()");
         print!("{}", cursor::Show);
@@ -1685,8 +2738,10 @@ impl Screen {
                 });
                 if contains {
                     self.arrows.retain(|e| e != &arrow);
+                    self.undo.record(Op::RemoveArrow(from, to));
                 } else {
                     self.arrows.push(arrow);
+                    self.undo.record(Op::AddArrow(from, to));
                 }
             }
         }
@@ -1727,7 +2782,13 @@ impl Screen {
 
         // clean up before a fresh drawing
         self.ephemeral_max_id = std::u64::MAX;
+        for id in self.ephemeral_nodes.keys() {
+            // ids are handed out again next frame
+            self.grapheme_cache.remove(id);
+        }
         self.ephemeral_nodes.clear();
+        self.ephemeral_targets.clear();
+        self.ephemeral_ids.clear();
         self.lookup.clear();
         self.drawn_at.clear();
         self.lowest_drawn = 0;
@@ -1741,6 +2802,9 @@ impl Screen {
         // (this only happens when draw_header() is above
         // the call to draw_children_of_root()...
         self.draw_header();
+        if self.show_calendar {
+            self.draw_calendar();
+        }
 
         // print logs
         if self.show_logs && self.dims.0 > 4 && self.dims.1 > 7 {
@@ -1790,6 +2854,8 @@ impl Screen {
             }
         }
 
+        self.draw_tag_completions();
+
         // show scrollbar if we've drawn anything below the bottom of the screen
         if self.lowest_drawn > self.dims.1 {
             self.draw_scrollbar();
@@ -1803,6 +2869,46 @@ impl Screen {
         // debug!("draw time: {}", after - before);
     }
 
+    // lists the tags that the one being typed may become below its node
+    fn draw_tag_completions(&self) {
+        let selected_id = match self.selected {
+            Some(selected_id) => selected_id,
+            None => return,
+        };
+        let (candidates, current) = match self.tag_completions {
+            Some((ref candidates, idx)) => (candidates.clone(), Some((idx - 1) % candidates.len())),
+            None => match self.partial_tag(selected_id) {
+                Some(partial) => (self.tag_candidates(selected_id, &partial), None),
+                None => return,
+            },
+        };
+        let (x, y) = match self
+            .drawn_at(selected_id)
+            .and_then(|&coords| self.internal_to_screen_xy(coords))
+        {
+            Some(coords) => coords,
+            None => return,
+        };
+        let start = current.unwrap_or(0) / MAX_TAG_COMPLETIONS * MAX_TAG_COMPLETIONS;
+        let shown = candidates
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_TAG_COMPLETIONS);
+        for (row, (i, tag)) in shown.enumerate() {
+            let y = y + 1 + row as u16;
+            if y >= self.dims.1 {
+                break;
+            }
+            print!("{}", cursor::Goto(x, y));
+            if Some(i) == current {
+                print!("│{}#{}{}", style::Invert, tag, style::Reset);
+            } else {
+                print!("│#{}", tag);
+            }
+        }
+    }
+
     fn draw_scrollbar(&self) {
         let bar_height = max(self.dims.1, 1) - 1;
         let normalized_lowest = f64::from(max(self.lowest_drawn, 1));
@@ -1870,13 +2976,14 @@ impl Screen {
             })
             .cloned()
             .unwrap();
-        let node = if raw_node.selected {
-            let mut formatted = self.format_node(&raw_node);
-            formatted.content = raw_node.content;
-            formatted
-        } else {
-            self.format_node(&raw_node)
-        };
+        let mut node = self.format_node(&raw_node);
+        // a chart's rows after the first go below the node
+        let chart_rows: Vec<String> = node.content.split('\n').skip(1).map(String::from).collect();
+        if raw_node.selected {
+            node.content = raw_node.content;
+        } else if !chart_rows.is_empty() {
+            node.content.truncate(node.content.find('\n').unwrap());
+        }
         if node.stricken && hide_stricken {
             return 0;
         }
@@ -1903,6 +3010,8 @@ impl Screen {
             }
             if node.stricken {
                 write!(&mut buf, "☠").unwrap();
+            } else if ephemeral {
+                write!(&mut buf, "↪").unwrap();
             } else if node.collapsed {
                 write!(&mut buf, "⊞").unwrap();
             } else if node.hide_stricken {
@@ -1918,6 +3027,15 @@ impl Screen {
             }
 
             write!(&mut buf, "{}", node.content).unwrap();
+            if !node.stricken {
+                if let Some(marker) = node.meta.due_marker(now().as_secs()) {
+                    write!(&mut buf, " {}", marker).unwrap();
+                }
+            }
+            if let Some(last) = node.meta.measurements.last() {
+                let ago = trash::ago(last.time, now().as_secs());
+                write!(&mut buf, " [{} {}]", last.value, ago).unwrap();
+            }
 
             let max_width = (max(self.dims.0, 1 + x) - 1 - x) as usize;
             let visible_graphemes =
@@ -1973,6 +3091,26 @@ impl Screen {
         let prefix = prefix;
 
         let mut drawn = 1;
+        let connector = if node.collapsed || node.children.is_empty() {
+            "  "
+        } else {
+            "│ "
+        };
+        for row in &chart_rows {
+            let row_coords = (internal_coords.0, internal_coords.1 + drawn as u16);
+            let line = format!("{}{}{}", prefix, connector, row);
+            if let Some((x, y)) = self.internal_to_screen_xy(row_coords) {
+                let max_width = (max(self.dims.0, 1 + x) - 1 - x) as usize;
+                let visible: String = line.chars().take(max_width).collect();
+                print!("{}{}{}{}", cursor::Goto(x, y), color, visible, reset);
+            }
+            let width = line.chars().count() as u16;
+            for x in row_coords.0..(row_coords.0 + width) {
+                self.lookup.insert((x, row_coords.1), node_id);
+            }
+            self.lowest_drawn = max(self.lowest_drawn, row_coords.1);
+            drawn += 1;
+        }
         if !node.collapsed {
             let n_children = node.children.len();
             for (n, &child) in node.children.iter().enumerate() {
@@ -2076,6 +3214,25 @@ impl Screen {
         }
     }
 
+    // a year of everything finished, between the header and the map
+    fn draw_calendar(&self) {
+        if self.calendar_rows() == 0 {
+            return;
+        }
+        let finished = self
+            .recursive_child_filter_map(0, &mut |n: &Node| n.meta.finish_time.map(|t| t as i64));
+        let weeks = min(52, self.dims.0 as usize - 3);
+        let lines = plot::calendar(&finished, weeks, now().as_secs() as i64);
+        for (i, line) in lines.iter().enumerate() {
+            print!(
+                "{}{} {}",
+                cursor::Goto(1, i as u16 + 2),
+                clear::CurrentLine,
+                line
+            );
+        }
+    }
+
     fn path_from_node_to_point(&self, start: NodeID, to: Coords) -> (Vec<Coords>, (Dir, Dir)) {
         // TODO this is mostly copypasta from path_between_nodes, DRY
         trace!("getting path between node {} and point {:?}", start, to);
@@ -2221,118 +3378,178 @@ impl Screen {
     }
 
     fn format_node(&mut self, raw_node: &Node) -> Node {
-        lazy_static! {
-            //// general subtree population and modification
-            // limit shows the top N results.
-            static ref RE_LIMIT: Regex = Regex::new(r"#limit=(\d+)").unwrap();
-            static ref RE_TAGGED: Regex = Regex::new(r"#tagged=(\S+)").unwrap();
-            static ref RE_REV: Regex = Regex::new(r"#rev\b").unwrap();
-            static ref RE_DONE: Regex = Regex::new(r"#done\b").unwrap();
-            static ref RE_OPEN: Regex = Regex::new(r"#open\b").unwrap();
-            // since defaults to last week
-            static ref RE_SINCE: Regex = Regex::new(r"#since=(\S+)").unwrap();
-            // until defaults until now
-            static ref RE_UNTIL: Regex = Regex::new(r"#until=(\S+)").unwrap();
-
-            //// plot specific
-            // plot can be {new,done}
-            static ref RE_PLOT: Regex = Regex::new(r"#plot=(\S+)").unwrap();
-            // n is the number of buckets
-            static ref RE_N: Regex = Regex::new(r"#n=(\d+)").unwrap();
-        }
-
-        // TODO detect and avoid cycles
-        // NB avoid cycles
         let mut node = raw_node.clone();
 
-        // for tagged queries, AND queries together
-        let mut tagged_children: Option<HashSet<NodeID>> = None;
-        for tag in &re_matches::<String>(&RE_TAGGED, &*node.content) {
-            let children = self.tag_db.tag_to_nodes(tag);
-            if let Some(children_acc) = tagged_children {
-                let children_new = children.into_iter().collect();
-                let intersection = children_acc.intersection(&children_new);
-                tagged_children = Some(intersection.cloned().collect());
-            } else {
-                tagged_children = Some(children.into_iter().collect());
-            }
+        let mut query = Query::parse(&node.content);
+        if let Some(e) = node.due_tag_error() {
+            query.errors.push(e.to_string());
         }
-        let queried_nodes = tagged_children
-            .map(|tc| tc.into_iter().collect())
-            .unwrap_or_else(|| vec![]);
+        node.children = query.filter(node.children, &self.nodes, &self.tag_db);
 
-        let mut since_opt = None;
-        let mut until_opt = None;
-        if RE_DONE.is_match(&*node.content) {
-            for child in node.children.clone() {
-                let done = self.with_node(child, |c| c.stricken).unwrap();
-                if !done {
-                    node.children.retain(|&c| c != child);
+        // say what's wrong rather than showing an empty result
+        if !query.errors.is_empty() {
+            node.content = format!("{} ⚠ {}", node.content, query.errors.join(", "));
+        } else if let Some(plot) = query.plot {
+            let queried_nodes = query
+                .tagged_nodes(&self.tag_db, &self.nodes)
+                .unwrap_or_default();
+            let now = now().as_secs();
+            // a calendar's buckets are weeks
+            let buckets = match plot {
+                PlotType::Calendar => query.n.unwrap_or(52),
+                _ => query.n.unwrap_or(7),
+            };
+            let since = query.since.unwrap_or_else(|| now - 60 * 60 * 24 * 7);
+            let until = query.until.unwrap_or(now);
+            let height = min(query.h.unwrap_or(1), MAX_CHART_HEIGHT);
+
+            node.content = match (plot, node.meta.due) {
+                // burndowns run up to the due date
+                (PlotType::Burndown, Some(due)) => {
+                    self.plot(queried_nodes, plot, buckets, since, due, height)
                 }
-            }
-        }
-        if RE_OPEN.is_match(&*node.content) {
-            for child in node.children.clone() {
-                let open = self.with_node(child, |c| !c.stricken).unwrap();
-                if !open {
-                    node.children.retain(|&c| c != child);
+                (PlotType::Burndown, None) => {
+                    format!("{} ⚠ burndown needs a #due= date", node.content)
                 }
-            }
-        }
-        if let Some(since) = re_matches::<String>(&RE_SINCE, &*node.content).get(0) {
-            since_opt = dateparse(since.clone());
-            if let Some(cutoff) = since_opt {
-                let mut new = vec![];
-                for &c in &node.children {
-                    let valid = self
-                        .with_node(c, |c| c.meta.mtime >= cutoff)
-                        .unwrap_or(false);
-                    if valid {
-                        new.push(c);
+                _ => self.plot(queried_nodes, plot, buckets, since, until, height),
+            };
+        } else if query.tagged_nodes(&self.tag_db, &self.nodes).is_some() {
+            // matches are listed below the query node, and matches that
+            // are query nodes list theirs in turn, unless that would loop
+            // back around or run past the depth and node budgets
+            let (lineage, depth) = self.view_lineage(node.id);
+            if lineage[1..].contains(&lineage[0]) {
+                node.content.push_str(" ↻ cycle");
+            } else if depth > MAX_QUERY_DEPTH {
+                node.content.push_str(" ⋯");
+            } else {
+                let mut hidden = 0;
+                for target in self.query(&query, false) {
+                    match self.ephemeral_node(node.id, target) {
+                        Some(ephemeral_id) => node.children.push(ephemeral_id),
+                        None => hidden += 1,
                     }
                 }
-                node.children = new;
-            }
-        }
-        if let Some(until) = re_matches::<String>(&RE_UNTIL, &*node.content).get(0) {
-            until_opt = dateparse(until.clone());
-            if let Some(cutoff) = until_opt {
-                let mut new = vec![];
-                for &c in &node.children {
-                    let valid = self
-                        .with_node(c, |c| c.meta.mtime <= cutoff)
-                        .unwrap_or(false);
-                    if valid {
-                        new.push(c);
-                    }
+                if hidden > 0 {
+                    write!(node.content, " ⚠ {} more not shown", hidden).unwrap();
                 }
-                node.children = new;
             }
         }
-        if RE_REV.is_match(&*node.content) {
-            node.children = node.children.into_iter().rev().collect();
+
+        match node.habit_period() {
+            Some(Ok(every)) => {
+                // periods count back from the end of today
+                let end = dateparse("tomorrow").unwrap_or_else(|_| now().as_secs());
+                let (current, longest) = node.meta.streaks(every, end);
+                let counts = node
+                    .meta
+                    .completions_per_period(every, end, query.n.unwrap_or(7));
+                let line = plot::plot_sparkline(counts);
+                write!(
+                    node.content,
+                    " streak {} (best {}) |{}|",
+                    current, longest, line
+                )
+                .unwrap();
+            }
+            Some(Err(e)) => write!(node.content, " ⚠ {}", e).unwrap(),
+            None => {}
         }
-        if let Some(&limit) = re_matches(&RE_LIMIT, &*node.content).get(0) {
-            node.children.truncate(limit);
+        node
+    }
+
+    // the real ids of a node and everything above it in the current
+    // view, along with how many of them are query matches
+    fn view_lineage(&self, node_id: NodeID) -> (Vec<NodeID>, usize) {
+        let mut lineage = vec![];
+        let mut depth = 0;
+        let mut cursor = node_id;
+        loop {
+            match self.ephemeral_targets.get(&cursor) {
+                Some(&target) => {
+                    lineage.push(target);
+                    depth += 1;
+                }
+                None => lineage.push(cursor),
+            }
+            let parent = self
+                .nodes
+                .get(&cursor)
+                .or_else(|| self.ephemeral_nodes.get(&cursor))
+                .map(|n| n.parent_id);
+            match parent {
+                Some(parent) if cursor != 0 => cursor = parent,
+                _ => break,
+            }
         }
+        (lineage, depth)
+    }
 
-        let re_n = re_matches::<usize>(&RE_N, &*node.content);
-        let n_opt = re_n.get(0);
-        if let Some(plot) = re_matches::<String>(&RE_PLOT, &*node.content).get(0) {
-            let now = now().as_secs();
-            let buckets = n_opt.cloned().unwrap_or(7);
-            let since = since_opt.unwrap_or_else(|| now - 60 * 60 * 24 * 7);
-            let until = until_opt.unwrap_or_else(|| now);
-
-            node.content = match plot.as_str() {
-                "done" => self.plot(queried_nodes, PlotType::Done, buckets, since, until),
-                "new" => self.plot(queried_nodes, PlotType::New, buckets, since, until),
-                _ => node.content,
-            };
+    // a read-only copy of a query match, reused for the rest of the frame
+    fn ephemeral_node(&mut self, parent_id: NodeID, target: NodeID) -> Option<NodeID> {
+        if let Some(&id) = self.ephemeral_ids.get(&(parent_id, target)) {
+            return Some(id);
         }
-        node
+        if self.ephemeral_nodes.len() >= MAX_EPHEMERAL_NODES {
+            return None;
+        }
+        self.ephemeral_max_id -= 1;
+        let id = self.ephemeral_max_id;
+        let mut node = self.nodes[&target].clone();
+        node.id = id;
+        node.parent_id = parent_id;
+        node.children = vec![];
+        node.selected = false;
+        node.collapsed = false;
+        self.ephemeral_nodes.insert(id, node);
+        self.ephemeral_targets.insert(id, target);
+        self.ephemeral_ids.insert((parent_id, target), id);
+        Some(id)
+    }
+
+    fn drawable_node(&self, node_id: NodeID) -> Option<Node> {
+        self.nodes
+            .get(&node_id)
+            .or_else(|| self.ephemeral_nodes.get(&node_id))
+            .cloned()
+    }
+
+    // the tags a node carries itself or inherits from its ancestors
+    pub fn effective_tags(&self, node_id: NodeID) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .tag_db
+            .effective_tags(node_id, &self.nodes)
+            .into_iter()
+            .collect();
+        tags.sort();
+        tags
+    }
+
+    // the nodes tagged as the query asks, or every node if no tags are
+    // given, ordered by creation, just as a query node lists them. with
+    // `subtree` everything below the tagged nodes is considered too.
+    pub fn query(&self, query: &Query, subtree: bool) -> Vec<NodeID> {
+        let mut node_ids: Vec<NodeID> = match query.tagged_nodes(&self.tag_db, &self.nodes) {
+            Some(tagged) if subtree => {
+                let mut all = vec![];
+                for node_id in tagged {
+                    let mut subtree =
+                        self.recursive_child_filter_map(node_id, &mut |n: &Node| Some(n.id));
+                    all.append(&mut subtree);
+                }
+                all
+            }
+            Some(tagged) => tagged,
+            None => self.nodes.keys().cloned().collect(),
+        };
+        node_ids.retain(|&id| id != 0 && self.nodes.contains_key(&id));
+        node_ids.sort_by_key(|id| (self.nodes[id].meta.ctime, *id));
+        node_ids.dedup();
+        query.filter(node_ids, &self.nodes, &self.tag_db)
     }
 
+    // a sparkline, or a chart with a line per row if `height` is more
+    // than one or it's a calendar, which draw_node spreads below the node
     fn plot(
         &self,
         queried_nodes: Vec<NodeID>,
@@ -2340,30 +3557,32 @@ impl Screen {
         buckets: usize,
         since: u64,
         until: u64,
+        height: usize,
     ) -> String {
-        let mut nodes = vec![];
+        // tagged nodes may be nested, count each one once. each is
+        // the span from its creation to when it was finished, if ever.
+        let mut seen = HashSet::new();
+        let mut spans = vec![];
         for &c in &queried_nodes {
-            let mut new = self.recursive_child_filter_map(c, &mut |n: &Node| match kind {
-                PlotType::Done => {
-                    if let Some(ft) = n.meta.finish_time {
-                        if ft >= since {
-                            return Some(ft as i64);
-                        }
-                    }
+            let mut new = self.recursive_child_filter_map(c, &mut |n: &Node| {
+                if seen.insert(n.id) {
+                    Some((n.meta.ctime as i64, n.meta.finish_time.map(|t| t as i64)))
+                } else {
                     None
                 }
-                PlotType::New => {
-                    if n.meta.ctime >= since {
-                        Some(n.meta.ctime as i64)
-                    } else {
-                        None
-                    }
-                }
             });
-            nodes.append(&mut new);
+            spans.append(&mut new);
+        }
+        let (since, until, now) = (since as i64, until as i64, now().as_secs() as i64);
+        if kind == PlotType::Calendar {
+            let finished: Vec<i64> = spans.iter().filter_map(|s| s.1).collect();
+            plot::calendar(&finished, buckets, until).join("\n")
+        } else if height > 1 {
+            plot::chart_spans(kind, &spans, since, until, buckets, now, height).join("\n")
+        } else {
+            let line = plot::plot_spans(kind, &spans, since, until, buckets, now);
+            format!("|{}|", line)
         }
-        let plot = plot::bounded_count_sparkline(nodes, since as i64, until as i64, buckets);
-        format!("|{}|", plot)
     }
 }
 
@@ -2373,9 +3592,62 @@ enum SearchDirection {
     Backward,
 }
 
-enum PlotType {
-    New,
-    Done,
+// what follows `from` in a tag that is `from`, `from=value` or nested below it
+fn tag_suffix<'a>(tag: &'a str, from: &str) -> Option<&'a str> {
+    let rest = tag.strip_prefix(from)?;
+    if rest.is_empty() || rest.starts_with('=') || rest.starts_with('/') {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+fn rename_tag_in(text: &str, from: &str, to: &str) -> Option<String> {
+    lazy_static! {
+        static ref RE_TAG: Regex = Regex::new(r"#(\S+)").unwrap();
+        static ref RE_Q: Regex = Regex::new(r"#q=([^#]*)").unwrap();
+        // a tag word along with the space before it
+        static ref RE_SPACED_TAG: Regex = Regex::new(r"(^|\s+)(#\S+)").unwrap();
+    }
+    let mut changed = false;
+    let renamed = RE_Q.replace_all(text, |caps: &Captures| {
+        match TagExpr::rename_tag(&caps[1], from, to) {
+            Some(expr) => {
+                changed = true;
+                format!("#q={}", expr)
+            }
+            None => caps[0].to_owned(),
+        }
+    });
+    let renamed = RE_TAG.replace_all(&renamed, |caps: &Captures| {
+        let tag = &caps[1];
+        let renamed = if let Some(tagged) = tag.strip_prefix("tagged=") {
+            tag_suffix(tagged, from).map(|rest| format!("#tagged={}{}", to, rest))
+        } else if let Some(sort) = tag.strip_prefix("sort=") {
+            // the key may be followed by an order, like :desc
+            let (key, order) = sort.split_at(sort.find(':').unwrap_or(sort.len()));
+            tag_suffix(key, from).map(|rest| format!("#sort={}{}{}", to, rest, order))
+        } else {
+            tag_suffix(tag, from).map(|rest| format!("#{}{}", to, rest))
+        };
+        changed |= renamed.is_some();
+        renamed.unwrap_or_else(|| caps[0].to_owned())
+    });
+    if !changed {
+        return None;
+    }
+    // merged tags may now appear twice, so drop the later ones. other
+    // repeated tags are left as they are.
+    let mut seen = HashSet::new();
+    let deduped = RE_SPACED_TAG.replace_all(&renamed, |caps: &Captures| {
+        let merged = tag_suffix(&caps[2][1..], to).is_some();
+        if !merged || seen.insert(caps[2].to_owned()) {
+            caps[0].to_owned()
+        } else {
+            String::new()
+        }
+    });
+    Some(deduped.into_owned())
 }
 
 fn visible(view_y: u16, height: u16, y: u16) -> bool {
@@ -1,30 +1,106 @@
+use std::collections::{HashMap, HashSet};
+
 use protobuf::{self, Message};
 
-use crate::{pb, random_fg_color, Meta, Node, Screen};
+use crate::{
+    json::Json, pb, random_fg_color, trash::TrashEntry, Measurement, Meta, Node, NodeID, Op,
+    Screen, UndoLog,
+};
 
 pub fn serialize_screen(screen: &Screen) -> Vec<u8> {
     let mut screen_pb = pb::Screen::default();
     screen_pb.set_max_id(screen.max_id);
-    let nodes = screen
-        .nodes
-        .iter()
-        .map(|(_, node)| serialize_node(node))
-        .collect();
+    screen_pb.set_trash_days(screen.trash_days);
+    // sorted so that the same screen always yields the same bytes
+    let mut nodes: Vec<&Node> = screen.nodes.values().collect();
+    nodes.sort_by_key(|n| n.id);
+    let nodes = nodes.into_iter().map(serialize_node).collect();
     screen_pb.set_nodes(protobuf::RepeatedField::from_vec(nodes));
-    let arrows = screen
-        .arrows
-        .iter()
-        .map(|&(from, to)| {
-            let mut arrow_pb = pb::Arrow::default();
-            arrow_pb.set_from_node(from);
-            arrow_pb.set_to_node(to);
-            arrow_pb
-        })
-        .collect();
+    let arrows = screen.arrows.iter().map(serialize_arrow).collect();
     screen_pb.set_arrows(protobuf::RepeatedField::from_vec(arrows));
+    let trash = screen.trash.iter().map(serialize_trash_entry).collect();
+    screen_pb.set_trash(protobuf::RepeatedField::from_vec(trash));
+    let (undo, redo) = screen.undo.history();
+    let undo = undo.into_iter().map(serialize_op).collect();
+    screen_pb.set_undo(protobuf::RepeatedField::from_vec(undo));
+    let redo = redo.iter().map(serialize_op).collect();
+    screen_pb.set_redo(protobuf::RepeatedField::from_vec(redo));
     screen_pb.write_to_bytes().unwrap()
 }
 
+fn serialize_arrow(&(from, to): &(NodeID, NodeID)) -> pb::Arrow {
+    let mut arrow_pb = pb::Arrow::default();
+    arrow_pb.set_from_node(from);
+    arrow_pb.set_to_node(to);
+    arrow_pb
+}
+
+fn serialize_trash_entry(entry: &TrashEntry) -> pb::TrashEntry {
+    let mut entry_pb = pb::TrashEntry::default();
+    entry_pb.set_parent_id(entry.parent_id);
+    entry_pb.set_position(entry.idx as u64);
+    entry_pb.set_deleted_at(entry.deleted_at);
+    let nodes = entry.nodes.iter().map(serialize_node).collect();
+    entry_pb.set_nodes(protobuf::RepeatedField::from_vec(nodes));
+    let arrows = entry.arrows.iter().map(serialize_arrow).collect();
+    entry_pb.set_arrows(protobuf::RepeatedField::from_vec(arrows));
+    entry_pb
+}
+
+fn serialize_subtree_op(
+    parent: NodeID,
+    idx: usize,
+    nodes: &[Node],
+    arrows: &[(NodeID, NodeID)],
+) -> pb::SubtreeOp {
+    let mut subtree_pb = pb::SubtreeOp::default();
+    subtree_pb.set_parent_id(parent);
+    subtree_pb.set_position(idx as u64);
+    let nodes = nodes.iter().map(serialize_node).collect();
+    subtree_pb.set_nodes(protobuf::RepeatedField::from_vec(nodes));
+    let arrows = arrows.iter().map(serialize_arrow).collect();
+    subtree_pb.set_arrows(protobuf::RepeatedField::from_vec(arrows));
+    subtree_pb
+}
+
+fn serialize_op(op: &Op) -> pb::Op {
+    let mut op_pb = pb::Op::default();
+    match *op {
+        Op::Edit {
+            ref before,
+            ref after,
+        } => {
+            let mut edit_pb = pb::EditOp::default();
+            let before = before.iter().map(serialize_node).collect();
+            edit_pb.set_before(protobuf::RepeatedField::from_vec(before));
+            let after = after.iter().map(serialize_node).collect();
+            edit_pb.set_after(protobuf::RepeatedField::from_vec(after));
+            op_pb.set_edit(edit_pb);
+        }
+        Op::Insert {
+            parent,
+            idx,
+            ref nodes,
+            ref arrows,
+        } => op_pb.set_insert(serialize_subtree_op(parent, idx, nodes, arrows)),
+        Op::Remove {
+            parent,
+            idx,
+            ref nodes,
+            ref arrows,
+        } => op_pb.set_remove(serialize_subtree_op(parent, idx, nodes, arrows)),
+        Op::AddArrow(from, to) => op_pb.set_add_arrow(serialize_arrow(&(from, to))),
+        Op::RemoveArrow(from, to) => op_pb.set_remove_arrow(serialize_arrow(&(from, to))),
+        Op::Group(ref ops) => {
+            let mut group_pb = pb::OpGroup::default();
+            let ops = ops.iter().map(serialize_op).collect();
+            group_pb.set_ops(protobuf::RepeatedField::from_vec(ops));
+            op_pb.set_group(group_pb);
+        }
+    }
+    op_pb
+}
+
 fn serialize_meta(meta: &Meta) -> pb::Meta {
     let mut meta_pb = pb::Meta::default();
     meta_pb.set_gps(pb::Gps::default());
@@ -33,17 +109,38 @@ fn serialize_meta(meta: &Meta) -> pb::Meta {
     if let Some(finish_time) = meta.finish_time {
         meta_pb.set_finish_time(finish_time);
     }
+    if let Some(due) = meta.due {
+        meta_pb.set_due(due);
+    }
     let mut tags = vec![];
-    for (tagk, tagv) in &meta.tags {
+    for (tagk, tagv) in sorted_tags(meta) {
         let mut tag = pb::Tag::default();
         tag.set_key(tagk.clone());
         tag.set_value(tagv.clone());
         tags.push(tag);
     }
     meta_pb.set_tags(protobuf::RepeatedField::from_vec(tags));
+    let measurements = meta
+        .measurements
+        .iter()
+        .map(|m| {
+            let mut measurement = pb::Measurement::default();
+            measurement.set_time(m.time);
+            measurement.set_value(m.value);
+            measurement
+        })
+        .collect();
+    meta_pb.set_measurements(protobuf::RepeatedField::from_vec(measurements));
+    meta_pb.set_completions(meta.completions.clone());
     meta_pb
 }
 
+fn sorted_tags(meta: &Meta) -> Vec<(&String, &String)> {
+    let mut tags: Vec<_> = meta.tags.iter().collect();
+    tags.sort();
+    tags
+}
+
 fn serialize_node(node: &Node) -> pb::Node {
     let mut node_pb = pb::Node::default();
     node_pb.set_id(node.id);
@@ -82,6 +179,15 @@ fn deserialize_meta(meta_pb: &pb::Meta) -> Meta {
             .iter()
             .map(|tag| (tag.get_key().to_owned(), tag.get_value().to_owned()))
             .collect(),
+        measurements: meta_pb
+            .get_measurements()
+            .iter()
+            .map(|m| Measurement {
+                time: m.get_time(),
+                value: m.get_value(),
+            })
+            .collect(),
+        completions: meta_pb.get_completions().to_vec(),
     }
 }
 
@@ -111,6 +217,9 @@ pub fn deserialize_screen(data: Vec<u8>) -> Result<Screen, protobuf::ProtobufErr
     let screen_pb: pb::Screen = protobuf::parse_from_bytes(&*data)?;
     let mut screen = Screen::default();
     screen.max_id = screen_pb.get_max_id();
+    if screen_pb.has_trash_days() {
+        screen.trash_days = screen_pb.get_trash_days();
+    }
     screen.nodes = screen_pb
         .get_nodes()
         .iter()
@@ -124,11 +233,574 @@ pub fn deserialize_screen(data: Vec<u8>) -> Result<Screen, protobuf::ProtobufErr
     screen.arrows = screen_pb
         .get_arrows()
         .iter()
-        .map(|arrow_pb| {
-            let from = arrow_pb.get_from_node();
-            let to = arrow_pb.get_to_node();
-            (from, to)
-        })
+        .map(deserialize_arrow)
+        .collect();
+    // an entry without a subtree has nothing to restore
+    screen.trash = screen_pb
+        .get_trash()
+        .iter()
+        .filter(|entry_pb| !entry_pb.get_nodes().is_empty())
+        .map(deserialize_trash_entry)
+        .collect();
+    screen.undo = UndoLog::from_history(
+        deserialize_ops(screen_pb.get_undo()),
+        deserialize_ops(screen_pb.get_redo()),
+    );
+    Ok(screen)
+}
+
+fn deserialize_arrow(arrow_pb: &pb::Arrow) -> (NodeID, NodeID) {
+    (arrow_pb.get_from_node(), arrow_pb.get_to_node())
+}
+
+fn deserialize_trash_entry(entry_pb: &pb::TrashEntry) -> TrashEntry {
+    TrashEntry {
+        parent_id: entry_pb.get_parent_id(),
+        idx: entry_pb.get_position() as usize,
+        deleted_at: entry_pb.get_deleted_at(),
+        nodes: entry_pb.get_nodes().iter().map(deserialize_node).collect(),
+        arrows: entry_pb
+            .get_arrows()
+            .iter()
+            .map(deserialize_arrow)
+            .collect(),
+    }
+}
+
+fn deserialize_ops(ops_pb: &[pb::Op]) -> Vec<Op> {
+    ops_pb.iter().map(deserialize_op).collect()
+}
+
+fn deserialize_op(op_pb: &pb::Op) -> Op {
+    let nodes = |nodes_pb: &[pb::Node]| nodes_pb.iter().map(deserialize_node).collect();
+    let arrows = |subtree_pb: &pb::SubtreeOp| {
+        subtree_pb
+            .get_arrows()
+            .iter()
+            .map(deserialize_arrow)
+            .collect()
+    };
+    if op_pb.has_edit() {
+        let edit_pb = op_pb.get_edit();
+        Op::Edit {
+            before: nodes(edit_pb.get_before()),
+            after: nodes(edit_pb.get_after()),
+        }
+    } else if op_pb.has_insert() {
+        let insert_pb = op_pb.get_insert();
+        Op::Insert {
+            parent: insert_pb.get_parent_id(),
+            idx: insert_pb.get_position() as usize,
+            nodes: nodes(insert_pb.get_nodes()),
+            arrows: arrows(insert_pb),
+        }
+    } else if op_pb.has_remove() {
+        let remove_pb = op_pb.get_remove();
+        Op::Remove {
+            parent: remove_pb.get_parent_id(),
+            idx: remove_pb.get_position() as usize,
+            nodes: nodes(remove_pb.get_nodes()),
+            arrows: arrows(remove_pb),
+        }
+    } else if op_pb.has_add_arrow() {
+        let (from, to) = deserialize_arrow(op_pb.get_add_arrow());
+        Op::AddArrow(from, to)
+    } else if op_pb.has_remove_arrow() {
+        let (from, to) = deserialize_arrow(op_pb.get_remove_arrow());
+        Op::RemoveArrow(from, to)
+    } else {
+        // an op with nothing set is an empty group, and does nothing
+        Op::Group(deserialize_ops(op_pb.get_group().get_ops()))
+    }
+}
+
+// the same data as the protobuf save file, as json for jq and other
+// tools. like the save file, nodes are sorted by id and tags by key,
+// so converting to json and back yields the same bytes.
+pub fn screen_to_json(screen: &Screen) -> String {
+    let mut nodes: Vec<&Node> = screen.nodes.values().collect();
+    nodes.sort_by_key(|n| n.id);
+    let (undo, redo) = screen.undo.history();
+    Json::Object(vec![
+        field("max_id", number(screen.max_id)),
+        field("trash_days", number(screen.trash_days)),
+        field(
+            "nodes",
+            Json::Array(nodes.into_iter().map(node_to_json).collect()),
+        ),
+        field("arrows", arrows_to_json(&screen.arrows)),
+        field(
+            "trash",
+            Json::Array(screen.trash.iter().map(trash_entry_to_json).collect()),
+        ),
+        field(
+            "undo",
+            Json::Array(undo.into_iter().map(op_to_json).collect()),
+        ),
+        field("redo", Json::Array(redo.iter().map(op_to_json).collect())),
+    ])
+    .pretty()
+}
+
+fn field(key: &str, value: Json) -> (String, Json) { (key.to_owned(), value) }
+
+fn number(n: u64) -> Json { Json::Number(n.to_string()) }
+
+fn optional_number(n: Option<u64>) -> Json { n.map(number).unwrap_or(Json::Null) }
+
+fn arrows_to_json(arrows: &[(NodeID, NodeID)]) -> Json {
+    Json::Array(
+        arrows
+            .iter()
+            .map(|&(from, to)| {
+                Json::Object(vec![field("from", number(from)), field("to", number(to))])
+            })
+            .collect(),
+    )
+}
+
+fn trash_entry_to_json(entry: &TrashEntry) -> Json {
+    Json::Object(vec![
+        field("parent_id", number(entry.parent_id)),
+        field("idx", number(entry.idx as u64)),
+        field("deleted_at", number(entry.deleted_at)),
+        field(
+            "nodes",
+            Json::Array(entry.nodes.iter().map(node_to_json).collect()),
+        ),
+        field("arrows", arrows_to_json(&entry.arrows)),
+    ])
+}
+
+fn subtree_op_to_json(
+    kind: &str,
+    parent: NodeID,
+    idx: usize,
+    nodes: &[Node],
+    arrows: &[(NodeID, NodeID)],
+) -> Json {
+    Json::Object(vec![
+        field("kind", Json::String(kind.to_owned())),
+        field("parent_id", number(parent)),
+        field("idx", number(idx as u64)),
+        field(
+            "nodes",
+            Json::Array(nodes.iter().map(node_to_json).collect()),
+        ),
+        field("arrows", arrows_to_json(arrows)),
+    ])
+}
+
+fn arrow_op_to_json(kind: &str, from: NodeID, to: NodeID) -> Json {
+    Json::Object(vec![
+        field("kind", Json::String(kind.to_owned())),
+        field("from", number(from)),
+        field("to", number(to)),
+    ])
+}
+
+fn op_to_json(op: &Op) -> Json {
+    match *op {
+        Op::Edit {
+            ref before,
+            ref after,
+        } => Json::Object(vec![
+            field("kind", Json::String("edit".to_owned())),
+            field(
+                "before",
+                Json::Array(before.iter().map(node_to_json).collect()),
+            ),
+            field(
+                "after",
+                Json::Array(after.iter().map(node_to_json).collect()),
+            ),
+        ]),
+        Op::Insert {
+            parent,
+            idx,
+            ref nodes,
+            ref arrows,
+        } => subtree_op_to_json("insert", parent, idx, nodes, arrows),
+        Op::Remove {
+            parent,
+            idx,
+            ref nodes,
+            ref arrows,
+        } => subtree_op_to_json("remove", parent, idx, nodes, arrows),
+        Op::AddArrow(from, to) => arrow_op_to_json("add_arrow", from, to),
+        Op::RemoveArrow(from, to) => arrow_op_to_json("remove_arrow", from, to),
+        Op::Group(ref ops) => Json::Object(vec![
+            field("kind", Json::String("group".to_owned())),
+            field("ops", Json::Array(ops.iter().map(op_to_json).collect())),
+        ]),
+    }
+}
+
+fn node_to_json(node: &Node) -> Json {
+    let tags = sorted_tags(&node.meta)
+        .into_iter()
+        .map(|(k, v)| (k.clone(), Json::String(v.clone())))
         .collect();
+    let meta = Json::Object(vec![
+        field("ctime", number(node.meta.ctime)),
+        field("mtime", number(node.meta.mtime)),
+        field("finish_time", optional_number(node.meta.finish_time)),
+        field("due", optional_number(node.meta.due)),
+        field("tags", Json::Object(tags)),
+        field(
+            "measurements",
+            Json::Array(
+                node.meta
+                    .measurements
+                    .iter()
+                    .map(|m| {
+                        Json::Object(vec![
+                            field("time", number(m.time)),
+                            field("value", Json::Number(m.value.to_string())),
+                        ])
+                    })
+                    .collect(),
+            ),
+        ),
+        field(
+            "completions",
+            Json::Array(node.meta.completions.iter().cloned().map(number).collect()),
+        ),
+    ]);
+    Json::Object(vec![
+        field("id", number(node.id)),
+        field("parent_id", number(node.parent_id)),
+        field("content", Json::String(node.content.clone())),
+        field(
+            "children",
+            Json::Array(node.children.iter().cloned().map(number).collect()),
+        ),
+        field("x", number(u64::from(node.rooted_coords.0))),
+        field("y", number(u64::from(node.rooted_coords.1))),
+        field("collapsed", Json::Bool(node.collapsed)),
+        field("stricken", Json::Bool(node.stricken)),
+        field("hide_stricken", Json::Bool(node.hide_stricken)),
+        field("auto_arrange", Json::Bool(node.auto_arrange)),
+        field(
+            "free_text",
+            node.free_text
+                .clone()
+                .map(Json::String)
+                .unwrap_or(Json::Null),
+        ),
+        field("meta", meta),
+    ])
+}
+
+// reads what screen_to_json writes, refusing anything that would
+// leave the tree inconsistent
+pub fn screen_from_json(text: &str) -> Result<Screen, String> {
+    let json = Json::parse(text)?;
+    let mut screen = Screen::default();
+    screen.max_id = get_u64(&json, "max_id")?;
+    // older exports leave it at the default
+    if let Some(trash_days) = get_optional_u64(&json, "trash_days")? {
+        screen.trash_days = trash_days;
+    }
+    screen.nodes = HashMap::new();
+    for node_json in get_array(&json, "nodes")? {
+        let node = node_from_json(node_json)?;
+        screen.tag_db.reindex(node.id, node.content.clone());
+        if let Some(node) = screen.nodes.insert(node.id, node) {
+            return Err(format!("node {} appears twice", node.id));
+        }
+    }
+    screen.arrows = arrows_from_json(&json)?;
+    screen.trash = get_array(&json, "trash")?
+        .iter()
+        .map(trash_entry_from_json)
+        .collect::<Result<_, _>>()?;
+    // nor an undo history
+    screen.undo =
+        UndoLog::from_history(ops_from_json(&json, "undo")?, ops_from_json(&json, "redo")?);
+    check_consistency(&screen)?;
     Ok(screen)
 }
+
+fn get<'a>(json: &'a Json, key: &str) -> Result<&'a Json, String> {
+    json.get(key).ok_or_else(|| format!("missing {}", key))
+}
+
+fn get_u64(json: &Json, key: &str) -> Result<u64, String> {
+    get(json, key)?
+        .as_u64()
+        .ok_or_else(|| format!("{} should be a positive integer", key))
+}
+
+fn get_optional_u64(json: &Json, key: &str) -> Result<Option<u64>, String> {
+    match json.get(key) {
+        None | Some(Json::Null) => Ok(None),
+        Some(_) => get_u64(json, key).map(Some),
+    }
+}
+
+fn get_bool(json: &Json, key: &str) -> Result<bool, String> {
+    get(json, key)?
+        .as_bool()
+        .ok_or_else(|| format!("{} should be true or false", key))
+}
+
+fn get_str<'a>(json: &'a Json, key: &str) -> Result<&'a str, String> {
+    get(json, key)?
+        .as_str()
+        .ok_or_else(|| format!("{} should be a string", key))
+}
+
+fn get_array<'a>(json: &'a Json, key: &str) -> Result<&'a [Json], String> {
+    get(json, key)?
+        .as_array()
+        .ok_or_else(|| format!("{} should be an array", key))
+}
+
+// older exports have no measurements
+fn measurements_from_json(json: &Json) -> Result<Vec<Measurement>, String> {
+    if json.get("measurements").is_none() {
+        return Ok(vec![]);
+    }
+    get_array(json, "measurements")?
+        .iter()
+        .map(|m| {
+            let value = get(m, "value")?
+                .as_f64()
+                .ok_or_else(|| "value should be a number".to_owned())?;
+            Ok(Measurement {
+                time: get_u64(m, "time")?,
+                value,
+            })
+        })
+        .collect()
+}
+
+// nor completions
+fn completions_from_json(json: &Json) -> Result<Vec<u64>, String> {
+    if json.get("completions").is_none() {
+        return Ok(vec![]);
+    }
+    get_array(json, "completions")?
+        .iter()
+        .map(|t| {
+            t.as_u64()
+                .ok_or_else(|| "completions should be positive integers".to_owned())
+        })
+        .collect()
+}
+
+fn arrows_from_json(json: &Json) -> Result<Vec<(NodeID, NodeID)>, String> {
+    get_array(json, "arrows")?
+        .iter()
+        .map(|arrow| Ok((get_u64(arrow, "from")?, get_u64(arrow, "to")?)))
+        .collect()
+}
+
+fn trash_entry_from_json(json: &Json) -> Result<TrashEntry, String> {
+    let nodes: Vec<Node> = get_array(json, "nodes")?
+        .iter()
+        .map(node_from_json)
+        .collect::<Result<_, _>>()?;
+    if nodes.is_empty() {
+        return Err("trash entry has no nodes".to_owned());
+    }
+    Ok(TrashEntry {
+        parent_id: get_u64(json, "parent_id")?,
+        idx: get_u64(json, "idx")? as usize,
+        deleted_at: get_u64(json, "deleted_at")?,
+        nodes,
+        arrows: arrows_from_json(json)?,
+    })
+}
+
+fn ops_from_json(json: &Json, key: &str) -> Result<Vec<Op>, String> {
+    if json.get(key).is_none() {
+        return Ok(vec![]);
+    }
+    get_array(json, key)?.iter().map(op_from_json).collect()
+}
+
+fn nodes_from_json(json: &Json, key: &str) -> Result<Vec<Node>, String> {
+    get_array(json, key)?.iter().map(node_from_json).collect()
+}
+
+fn op_from_json(json: &Json) -> Result<Op, String> {
+    let op = match get_str(json, "kind")? {
+        "edit" => Op::Edit {
+            before: nodes_from_json(json, "before")?,
+            after: nodes_from_json(json, "after")?,
+        },
+        "insert" => Op::Insert {
+            parent: get_u64(json, "parent_id")?,
+            idx: get_u64(json, "idx")? as usize,
+            nodes: nodes_from_json(json, "nodes")?,
+            arrows: arrows_from_json(json)?,
+        },
+        "remove" => Op::Remove {
+            parent: get_u64(json, "parent_id")?,
+            idx: get_u64(json, "idx")? as usize,
+            nodes: nodes_from_json(json, "nodes")?,
+            arrows: arrows_from_json(json)?,
+        },
+        "add_arrow" => Op::AddArrow(get_u64(json, "from")?, get_u64(json, "to")?),
+        "remove_arrow" => Op::RemoveArrow(get_u64(json, "from")?, get_u64(json, "to")?),
+        "group" => Op::Group(ops_from_json(json, "ops")?),
+        kind => return Err(format!("unknown undo op: {}", kind)),
+    };
+    Ok(op)
+}
+
+fn node_from_json(json: &Json) -> Result<Node, String> {
+    let id = get_u64(json, "id")?;
+    // name the node in errors about its fields
+    let in_node = |e: String| format!("node {}: {}", id, e);
+    let coord = |key| -> Result<u16, String> {
+        let n = get_u64(json, key)?;
+        if n > u64::from(u16::MAX) {
+            return Err(format!("{} is too large", key));
+        }
+        Ok(n as u16)
+    };
+    let meta_json = get(json, "meta").map_err(in_node)?;
+    let tags = get(meta_json, "tags")
+        .and_then(|tags| {
+            tags.as_object()
+                .ok_or_else(|| "tags should be an object".to_owned())
+        })
+        .map_err(in_node)?
+        .iter()
+        .map(|(k, v)| match v.as_str() {
+            Some(v) => Ok((k.clone(), v.to_owned())),
+            None => Err(in_node(format!("tag {} should be a string", k))),
+        })
+        .collect::<Result<_, _>>()?;
+    let meta = Meta {
+        ctime: get_u64(meta_json, "ctime").map_err(in_node)?,
+        mtime: get_u64(meta_json, "mtime").map_err(in_node)?,
+        finish_time: get_optional_u64(meta_json, "finish_time").map_err(in_node)?,
+        due: get_optional_u64(meta_json, "due").map_err(in_node)?,
+        tags,
+        measurements: measurements_from_json(meta_json).map_err(in_node)?,
+        completions: completions_from_json(meta_json).map_err(in_node)?,
+    };
+    let children = get_array(json, "children")
+        .map_err(in_node)?
+        .iter()
+        .map(|c| {
+            c.as_u64()
+                .ok_or_else(|| in_node("children should be ids".to_owned()))
+        })
+        .collect::<Result<_, _>>()?;
+    let free_text = match json.get("free_text") {
+        None | Some(Json::Null) => None,
+        Some(_) => Some(get_str(json, "free_text").map_err(in_node)?.to_owned()),
+    };
+    Ok(Node {
+        parent_id: get_u64(json, "parent_id").map_err(in_node)?,
+        rooted_coords: (coord("x").map_err(in_node)?, coord("y").map_err(in_node)?),
+        content: get_str(json, "content").map_err(in_node)?.to_owned(),
+        children,
+        selected: false,
+        collapsed: get_bool(json, "collapsed").map_err(in_node)?,
+        stricken: get_bool(json, "stricken").map_err(in_node)?,
+        hide_stricken: get_bool(json, "hide_stricken").map_err(in_node)?,
+        meta,
+        id,
+        free_text,
+        color: random_fg_color(),
+        auto_arrange: get_bool(json, "auto_arrange").map_err(in_node)?,
+    })
+}
+
+// everything Screen::assert_node_consistency checks, as errors
+fn check_consistency(screen: &Screen) -> Result<(), String> {
+    if !screen.nodes.contains_key(&0) {
+        return Err("there is no root node with id 0".to_owned());
+    }
+    for node in screen.nodes.values() {
+        if node.id > screen.max_id {
+            return Err(format!("node {} is above max_id", node.id));
+        }
+        for child in &node.children {
+            match screen.nodes.get(child) {
+                Some(c) if c.parent_id == node.id => {}
+                Some(_) => {
+                    return Err(format!(
+                        "node {} is a child of {} but has another parent_id",
+                        child, node.id
+                    ))
+                }
+                None => return Err(format!("node {} has a missing child {}", node.id, child)),
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut stack = vec![0];
+    while let Some(node_id) = stack.pop() {
+        if !seen.insert(node_id) {
+            return Err(format!(
+                "node {} appears more than once in the tree",
+                node_id
+            ));
+        }
+        stack.extend(&screen.nodes[&node_id].children);
+    }
+    if let Some(orphan) = screen.nodes.keys().filter(|id| !seen.contains(id)).min() {
+        return Err(format!("node {} isn't below the root", orphan));
+    }
+
+    for &(from, to) in &screen.arrows {
+        if !screen.nodes.contains_key(&from) || !screen.nodes.contains_key(&to) {
+            return Err(format!("arrow from {} to {} has a missing end", from, to));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_json_round_trip() {
+    let mut screen = Screen::default();
+    let project = screen.add_node(0, "plans #work".to_owned()).unwrap();
+    let first = screen
+        .add_node(project, "first \"one\"".to_owned())
+        .unwrap();
+    let gone = screen.add_node(0, "gone".to_owned()).unwrap();
+    screen.finish_node(first);
+    screen.remove_node(gone);
+    screen.arrows.push((first, project));
+    screen.trash_days = 7;
+    {
+        let node = screen.nodes.get_mut(&project).unwrap();
+        node.free_text = Some("line one\nline two".to_owned());
+        node.meta.due = Some(42);
+        node.meta.tags.insert("prio".to_owned(), "3".to_owned());
+        node.meta.tags.insert("a".to_owned(), "".to_owned());
+        node.meta.record(40, 72.4);
+        node.meta.record(41, -1.0);
+        node.meta.complete(43);
+    }
+
+    let data = serialize_screen(&screen);
+    let json = screen_to_json(&screen);
+    let from_json = screen_from_json(&json).unwrap();
+    assert_eq!(serialize_screen(&from_json), data);
+    let from_pb = deserialize_screen(data).unwrap();
+    assert_eq!(from_pb.trash_days, 7);
+    assert_eq!(screen_to_json(&from_pb), json);
+
+    let broken = json.replacen("\"parent_id\": 1", "\"parent_id\": 0", 1);
+    assert!(screen_from_json(&broken).is_err());
+    assert!(screen_from_json("{}").is_err());
+
+    let mut emptied = screen_from_json(&json).unwrap();
+    emptied.trash[0].nodes.clear();
+    let emptied_json = screen_to_json(&emptied);
+    assert_eq!(
+        screen_from_json(&emptied_json).err(),
+        Some("trash entry has no nodes".to_owned())
+    );
+    let from_pb = deserialize_screen(serialize_screen(&emptied)).unwrap();
+    assert!(from_pb.trash.is_empty());
+}
@@ -2,7 +2,16 @@ use std::collections::{HashMap, HashSet};
 
 use regex::Regex;
 
-use crate::{re_matches, NodeID};
+use crate::{re_matches, Node, NodeID};
+
+// how many nodes carry a tag, and how many of them are done
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagCount {
+    pub tag: String,
+    pub total: usize,
+    pub open: usize,
+    pub done: usize,
+}
 
 pub struct TagDB {
     node_to_tags: HashMap<NodeID, HashSet<String>>,
@@ -21,13 +30,23 @@ impl Default for TagDB {
 impl TagDB {
     pub fn reindex(&mut self, node: NodeID, text: String) {
         lazy_static! {
-            static ref RE_TAG_KEY: Regex = Regex::new(r"#([^\s=]+)*").unwrap();
+            // only at the start of a word, so `#q=#task` is not #task
+            static ref RE_TAG_KEY: Regex = Regex::new(r"(?:^|\s)#([^\s=]+)").unwrap();
             static ref RE_TAG_KEY_VALUE: Regex = Regex::new(r"#(\S+)*").unwrap();
         }
 
         self.remove(node);
         self.node_to_tags.insert(node, HashSet::new());
-        let tags = re_matches::<String>(&RE_TAG_KEY_VALUE, &*text);
+        let mut tags = re_matches::<String>(&RE_TAG_KEY_VALUE, &text);
+        // hierarchical tags like #proj/void/ui are also #proj/void and #proj
+        let mut prefixes = vec![];
+        for tag in &tags {
+            let key = tag.split('=').next().unwrap_or("");
+            for (i, _) in key.match_indices('/').filter(|&(i, _)| i > 0) {
+                prefixes.push(key[..i].to_owned());
+            }
+        }
+        tags.append(&mut prefixes);
 
         for tag in &tags {
             if let Some(tags) = self.node_to_tags.get_mut(&node) {
@@ -77,6 +96,96 @@ impl TagDB {
         res.sort();
         res
     }
+
+    // every node that has been indexed, tagged or not
+    pub fn nodes(&self) -> Vec<NodeID> { self.node_to_tags.keys().cloned().collect() }
+
+    // the values of `key=value` tags along with the nodes carrying them
+    pub fn values(&self, key: &str) -> Vec<(&str, Vec<NodeID>)> {
+        let prefix = format!("{}=", key);
+        self.tag_to_nodes
+            .iter()
+            .filter(|(tag, nodes)| tag.starts_with(&prefix) && !nodes.is_empty())
+            .map(|(tag, nodes)| (&tag[prefix.len()..], nodes.iter().cloned().collect()))
+            .collect()
+    }
+
+    // the tags a node carries in its own text
+    pub fn node_tags(&self, node: NodeID) -> HashSet<String> {
+        self.node_to_tags.get(&node).cloned().unwrap_or_default()
+    }
+
+    // the tags a node carries along with those of its ancestors. for
+    // `key=value` tags the value closest to the node wins.
+    pub fn effective_tags(&self, node: NodeID, nodes: &HashMap<NodeID, Node>) -> HashSet<String> {
+        let mut tags = HashSet::new();
+        let mut keys = HashSet::new();
+        let mut cursor = node;
+        loop {
+            let own = self.node_tags(cursor);
+            let mut values = vec![];
+            for tag in own {
+                match tag.find('=') {
+                    Some(i) if keys.contains(&tag[..i]) => {}
+                    Some(i) => {
+                        values.push(tag[..i].to_owned());
+                        tags.insert(tag);
+                    }
+                    None => {
+                        tags.insert(tag);
+                    }
+                }
+            }
+            keys.extend(values);
+            match nodes.get(&cursor) {
+                Some(n) if cursor != 0 => cursor = n.parent_id,
+                _ => break,
+            }
+        }
+        tags
+    }
+
+    // all tags that are currently attached to at least one node
+    pub fn tags(&self) -> Vec<String> {
+        let mut res: Vec<String> = self
+            .tag_to_nodes
+            .iter()
+            .filter(|(_, nodes)| !nodes.is_empty())
+            .map(|(tag, _)| tag.clone())
+            .collect();
+        res.sort();
+        res
+    }
+
+    // every tag in use with its counts, most used first or by name
+    pub fn counts(&self, nodes: &HashMap<NodeID, Node>, by_name: bool) -> Vec<TagCount> {
+        let mut counts: Vec<TagCount> = self
+            .tag_to_nodes
+            .iter()
+            .filter_map(|(tag, ids)| {
+                let done = ids
+                    .iter()
+                    .filter(|id| nodes.get(id).is_some_and(|n| n.stricken))
+                    .count();
+                let total = ids.iter().filter(|id| nodes.contains_key(id)).count();
+                if total == 0 {
+                    return None;
+                }
+                Some(TagCount {
+                    tag: tag.clone(),
+                    total,
+                    open: total - done,
+                    done,
+                })
+            })
+            .collect();
+        if by_name {
+            counts.sort_by(|a, b| a.tag.cmp(&b.tag));
+        } else {
+            counts.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.tag.cmp(&b.tag)));
+        }
+        counts
+    }
 }
 
 #[test]
@@ -92,3 +201,79 @@ fn test_basic_func() {
     assert_eq!(tdb.tag_to_nodes("yes=ok"), vec![3]);
     assert_eq!(tdb.tag_to_nodes("$"), vec![4]);
 }
+
+#[test]
+fn test_hierarchy() {
+    let mut tdb = TagDB::default();
+    tdb.reindex(1, "a #proj/void/ui #prio=5 #x".to_owned());
+    tdb.reindex(2, "b #proj/other #prio=1".to_owned());
+    tdb.reindex(3, "c #url=http://x/y".to_owned());
+    assert_eq!(tdb.tag_to_nodes("proj"), vec![1, 2]);
+    assert_eq!(tdb.tag_to_nodes("proj/void"), vec![1]);
+    assert_eq!(tdb.tag_to_nodes("proj/void/ui"), vec![1]);
+    assert_eq!(tdb.tag_to_nodes("url=http:"), Vec::<NodeID>::new());
+
+    let mut nodes = HashMap::new();
+    for (id, parent_id) in [(0, 0), (1, 0), (2, 1), (3, 2)] {
+        nodes.insert(
+            id,
+            Node {
+                id,
+                parent_id,
+                ..Node::default()
+            },
+        );
+    }
+    let mut tags: Vec<String> = tdb.effective_tags(3, &nodes).into_iter().collect();
+    tags.sort();
+    assert_eq!(
+        tags,
+        vec![
+            "prio",
+            "prio=1",
+            "proj",
+            "proj/other",
+            "proj/void",
+            "proj/void/ui",
+            "url",
+            "url=http://x/y",
+            "x"
+        ]
+    );
+}
+
+#[test]
+fn test_counts() {
+    let mut tdb = TagDB::default();
+    tdb.reindex(1, "a #work #prio=2".to_owned());
+    tdb.reindex(2, "b #work".to_owned());
+    tdb.reindex(3, "c #home #work".to_owned());
+    // no longer in the tree
+    tdb.reindex(4, "d #gone".to_owned());
+    let mut nodes = HashMap::new();
+    for id in 1..4 {
+        nodes.insert(
+            id,
+            Node {
+                id,
+                stricken: id == 2,
+                ..Node::default()
+            },
+        );
+    }
+    let tags = |by_name| -> Vec<(String, usize, usize, usize)> {
+        tdb.counts(&nodes, by_name)
+            .into_iter()
+            .map(|c| (c.tag, c.total, c.open, c.done))
+            .collect()
+    };
+    let work = ("work".to_owned(), 3, 2, 1);
+    let home = ("home".to_owned(), 1, 1, 0);
+    let prio = ("prio".to_owned(), 1, 1, 0);
+    let prio2 = ("prio=2".to_owned(), 1, 1, 0);
+    assert_eq!(
+        tags(false),
+        vec![work.clone(), home.clone(), prio.clone(), prio2.clone()]
+    );
+    assert_eq!(tags(true), vec![home, prio, prio2, work]);
+}
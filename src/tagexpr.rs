@@ -0,0 +1,331 @@
+use std::{collections::HashSet, iter::Peekable, vec};
+
+use crate::{NodeID, TagDB};
+
+// a boolean query over tags, like `(work|oss) & !blocked & prio>=3`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagExpr {
+    Tag(String),
+    // a `key=value` tag whose value compares as a number
+    Cmp(String, Cmp, f64),
+    Not(Box<TagExpr>),
+    And(Box<TagExpr>, Box<TagExpr>),
+    Or(Box<TagExpr>, Box<TagExpr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cmp {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Cmp {
+    fn holds(self, a: f64, b: f64) -> bool {
+        match self {
+            Cmp::Eq => a == b,
+            Cmp::Lt => a < b,
+            Cmp::Le => a <= b,
+            Cmp::Gt => a > b,
+            Cmp::Ge => a >= b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Op(Cmp),
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+impl TagExpr {
+    pub fn parse(text: &str) -> Result<TagExpr, String> {
+        let (expr, end) = TagExpr::parse_prefix(text)?;
+        if text[end..].trim().is_empty() {
+            Ok(expr)
+        } else {
+            Err(format!(
+                "bad query {:?}: expected '&' or '|' between tags",
+                text
+            ))
+        }
+    }
+
+    // an expression at the start of the text, and where it ends, so
+    // that text can follow it: `work | oss list` is `work | oss`
+    pub fn parse_prefix(text: &str) -> Result<(TagExpr, usize), String> {
+        let tokens = tokenize(text).map_err(|e| format!("bad query {:?}: {}", text, e))?;
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+            end: 0,
+        };
+        parser
+            .or()
+            .and_then(|expr| match parser.peek() {
+                Some(&Token::Close) => Err("unmatched ')'".to_owned()),
+                _ => Ok((expr, parser.end)),
+            })
+            .map_err(|e| format!("bad query {:?}: {}", text, e))
+    }
+
+    // the text with the tag `from` and tags nested below it renamed to
+    // `to` in the expression at its start, if it has any. values
+    // compared against, like `new` in `state=new`, are left alone.
+    pub fn rename_tag(text: &str, from: &str, to: &str) -> Option<String> {
+        let (_, end) = TagExpr::parse_prefix(text).ok()?;
+        let tokens = tokenize(&text[..end]).ok()?;
+        let mut renamed = String::new();
+        let mut last = 0;
+        let mut after_op = false;
+        for (token, end) in tokens {
+            if let Token::Word(ref word) = token {
+                let rest = word
+                    .strip_prefix(from)
+                    .filter(|rest| rest.is_empty() || rest.starts_with('/'));
+                if let (Some(rest), false) = (rest, after_op) {
+                    renamed.push_str(&text[last..end - word.len()]);
+                    renamed.push_str(to);
+                    renamed.push_str(rest);
+                    last = end;
+                }
+            }
+            after_op = matches!(token, Token::Op(_));
+        }
+        if last == 0 {
+            return None;
+        }
+        renamed.push_str(&text[last..]);
+        Some(renamed)
+    }
+
+    // whether a node with the given tags satisfies the expression
+    pub fn matches(&self, tags: &HashSet<String>) -> bool {
+        match *self {
+            TagExpr::Tag(ref tag) => tags.contains(tag),
+            TagExpr::Cmp(ref key, cmp, rhs) => tags.iter().any(|tag| {
+                let value = tag
+                    .strip_prefix(key.as_str())
+                    .and_then(|t| t.strip_prefix('='));
+                value.is_some_and(|v| v.parse::<f64>().is_ok_and(|v| cmp.holds(v, rhs)))
+            }),
+            TagExpr::Not(ref inner) => !inner.matches(tags),
+            TagExpr::And(ref a, ref b) => a.matches(tags) && b.matches(tags),
+            TagExpr::Or(ref a, ref b) => a.matches(tags) || b.matches(tags),
+        }
+    }
+
+    // the ids of every node that satisfies the expression. negation
+    // is relative to every indexed node other than the root.
+    pub fn eval(&self, tag_db: &TagDB) -> HashSet<NodeID> {
+        match *self {
+            TagExpr::Tag(ref tag) => tag_db.tag_to_nodes(tag).into_iter().collect(),
+            TagExpr::Cmp(ref key, cmp, rhs) => tag_db
+                .values(key)
+                .into_iter()
+                .filter(|(value, _)| value.parse::<f64>().is_ok_and(|v| cmp.holds(v, rhs)))
+                .flat_map(|(_, nodes)| nodes)
+                .collect(),
+            TagExpr::Not(ref inner) => {
+                let excluded = inner.eval(tag_db);
+                tag_db
+                    .nodes()
+                    .into_iter()
+                    .filter(|id| *id != 0 && !excluded.contains(id))
+                    .collect()
+            }
+            TagExpr::And(ref a, ref b) => {
+                let a = a.eval(tag_db);
+                b.eval(tag_db)
+                    .into_iter()
+                    .filter(|id| a.contains(id))
+                    .collect()
+            }
+            TagExpr::Or(ref a, ref b) => {
+                let mut a = a.eval(tag_db);
+                a.extend(b.eval(tag_db));
+                a
+            }
+        }
+    }
+}
+
+// each token along with the offset just past it
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, String> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '&' => Token::And,
+            '|' => Token::Or,
+            '!' => Token::Not,
+            '<' | '>' | '=' => {
+                let or_equal = c != '=' && chars.peek().is_some_and(|&(_, n)| n == '=');
+                if or_equal {
+                    chars.next();
+                }
+                Token::Op(match (c, or_equal) {
+                    ('<', false) => Cmp::Lt,
+                    ('<', true) => Cmp::Le,
+                    ('>', false) => Cmp::Gt,
+                    ('>', true) => Cmp::Ge,
+                    _ => Cmp::Eq,
+                })
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || "()&|!<>=".contains(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                Token::Word(text[start..end].to_owned())
+            }
+        };
+        let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+        tokens.push((token, end));
+    }
+    if tokens.is_empty() {
+        return Err("it's empty".to_owned());
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Peekable<vec::IntoIter<(Token, usize)>>,
+    // just past the last token taken
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&mut self) -> Option<&Token> { self.tokens.peek().map(|(token, _)| token) }
+
+    fn next(&mut self) -> Option<Token> {
+        let (token, end) = self.tokens.next()?;
+        self.end = end;
+        Some(token)
+    }
+
+    fn or(&mut self) -> Result<TagExpr, String> {
+        let mut expr = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            expr = TagExpr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<TagExpr, String> {
+        let mut expr = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            expr = TagExpr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<TagExpr, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(TagExpr::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => Err("unmatched '('".to_owned()),
+                }
+            }
+            Some(Token::Word(key)) => self.comparison(key),
+            Some(Token::Close) => Err("expected a tag before ')'".to_owned()),
+            Some(_) => Err("expected a tag before an operator".to_owned()),
+            None => Err("expected a tag at the end".to_owned()),
+        }
+    }
+
+    fn comparison(&mut self, key: String) -> Result<TagExpr, String> {
+        let cmp = match self.peek() {
+            Some(&Token::Op(cmp)) => cmp,
+            _ => return Ok(TagExpr::Tag(key)),
+        };
+        self.next();
+        let value = match self.next() {
+            Some(Token::Word(value)) => value,
+            _ => return Err(format!("expected a value after {:?}", key)),
+        };
+        match value.parse::<f64>() {
+            Ok(number) => Ok(TagExpr::Cmp(key, cmp, number)),
+            Err(_) if cmp == Cmp::Eq => Ok(TagExpr::Tag(format!("{}={}", key, value))),
+            Err(_) => Err(format!("{:?} is not a number", value)),
+        }
+    }
+}
+
+#[test]
+fn test_tag_expr() {
+    let mut tag_db = TagDB::default();
+    tag_db.reindex(0, "home".to_owned());
+    tag_db.reindex(1, "a #work #prio=5".to_owned());
+    tag_db.reindex(2, "b #oss #blocked #prio=3".to_owned());
+    tag_db.reindex(3, "c #oss #prio=1 #state=new".to_owned());
+    tag_db.reindex(4, "d #prio=high".to_owned());
+    let eval = |text: &str| {
+        let mut ids: Vec<NodeID> = TagExpr::parse(text)
+            .unwrap()
+            .eval(&tag_db)
+            .into_iter()
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(eval("(work|oss) & !blocked & prio>=3"), vec![1]);
+    assert_eq!(eval("work | oss & prio<2"), vec![1, 3]);
+    assert_eq!(eval("!(work|oss)"), vec![4]);
+    assert_eq!(eval("prio=3 | prio>4.5"), vec![1, 2]);
+    assert_eq!(eval("state=new | prio=high"), vec![3, 4]);
+    assert_eq!(eval("nothing"), Vec::<NodeID>::new());
+    let tags = ["oss", "prio", "prio=3"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+    let matches = |text: &str| TagExpr::parse(text).unwrap().matches(&tags);
+    assert!(matches("oss & prio>2 & !work"));
+    assert!(!matches("prio=high | blocked"));
+
+    assert_eq!(
+        TagExpr::parse("(work").unwrap_err(),
+        "bad query \"(work\": unmatched '('"
+    );
+    assert!(TagExpr::parse("work oss").is_err());
+    assert_eq!(
+        TagExpr::parse_prefix("work | oss list"),
+        Ok((
+            TagExpr::Or(
+                Box::new(TagExpr::Tag("work".to_owned())),
+                Box::new(TagExpr::Tag("oss".to_owned()))
+            ),
+            10
+        ))
+    );
+    let rename = |text: &str| TagExpr::rename_tag(text, "wrok", "work");
+    assert_eq!(
+        rename("(wrok/ui | oss)&!wrok  list wrok"),
+        Some("(work/ui | oss)&!work  list wrok".to_owned())
+    );
+    assert_eq!(rename("wrok>=2"), Some("work>=2".to_owned()));
+    assert_eq!(rename("state=wrok | wroks"), None);
+    assert_eq!(rename("wrok &"), None);
+    assert!(TagExpr::parse("work &").is_err());
+    assert!(TagExpr::parse("prio>high").is_err());
+    assert!(TagExpr::parse("a)").is_err());
+    assert!(TagExpr::parse(" ").is_err());
+}
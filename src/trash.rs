@@ -0,0 +1,62 @@
+use crate::{Node, NodeID};
+
+pub const DEFAULT_TRASH_DAYS: u64 = 30;
+
+// a deleted subtree, kept around so that it may be restored
+// to where it came from, even in a later session
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrashEntry {
+    pub parent_id: NodeID,
+    pub idx: usize,
+    pub deleted_at: u64,
+    // in pre-order, root first
+    pub nodes: Vec<Node>,
+    pub arrows: Vec<(NodeID, NodeID)>,
+}
+
+impl TrashEntry {
+    pub fn root(&self) -> Option<NodeID> { self.nodes.first().map(|n| n.id) }
+
+    // a lone node that was never written into isn't worth keeping
+    pub fn is_empty(&self) -> bool {
+        match self.nodes.as_slice() {
+            [] => true,
+            [node] => node.content.is_empty() && node.free_text.is_none(),
+            _ => false,
+        }
+    }
+}
+
+// a short human description of how long ago `then` was
+pub fn ago(then: u64, now: u64) -> String {
+    let secs = now.saturating_sub(then);
+    match secs {
+        s if s < 60 => "just now".to_owned(),
+        s if s < 60 * 60 => format!("{}m ago", s / 60),
+        s if s < 60 * 60 * 24 => format!("{}h ago", s / (60 * 60)),
+        s => format!("{}d ago", s / (60 * 60 * 24)),
+    }
+}
+
+#[test]
+fn test_trash_entry() {
+    let mut node = Node {
+        id: 3,
+        ..Node::default()
+    };
+    let mut entry = TrashEntry {
+        parent_id: 0,
+        idx: 0,
+        deleted_at: 0,
+        nodes: vec![node.clone()],
+        arrows: vec![],
+    };
+    assert_eq!(entry.root(), Some(3));
+    assert!(entry.is_empty());
+    node.content.push('a');
+    entry.nodes = vec![node];
+    assert!(!entry.is_empty());
+
+    assert_eq!(ago(100, 130), "just now");
+    assert_eq!(ago(0, 60 * 60 * 49), "2d ago");
+}
@@ -0,0 +1,322 @@
+use std::collections::VecDeque;
+
+use crate::{Node, NodeID};
+
+pub const DEFAULT_UNDO_LIMIT: usize = 1000;
+
+// a reversible change to the tree, carrying everything
+// needed to apply it in either direction
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    // nodes were modified in place, the first node is the one to select
+    Edit {
+        before: Vec<Node>,
+        after: Vec<Node>,
+    },
+    // a subtree (in pre-order, root first) was attached to a parent
+    Insert {
+        parent: NodeID,
+        idx: usize,
+        nodes: Vec<Node>,
+        arrows: Vec<(NodeID, NodeID)>,
+    },
+    // a subtree was detached from its parent
+    Remove {
+        parent: NodeID,
+        idx: usize,
+        nodes: Vec<Node>,
+        arrows: Vec<(NodeID, NodeID)>,
+    },
+    AddArrow(NodeID, NodeID),
+    RemoveArrow(NodeID, NodeID),
+    // several ops undone and redone as one, applied in order
+    Group(Vec<Op>),
+}
+
+impl Op {
+    pub fn inverse(self) -> Op {
+        match self {
+            Op::Edit { before, after } => Op::Edit {
+                before: after,
+                after: before,
+            },
+            Op::Insert {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            } => Op::Remove {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            },
+            Op::Remove {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            } => Op::Insert {
+                parent,
+                idx,
+                nodes,
+                arrows,
+            },
+            Op::AddArrow(from, to) => Op::RemoveArrow(from, to),
+            Op::RemoveArrow(from, to) => Op::AddArrow(from, to),
+            Op::Group(ops) => Op::Group(ops.into_iter().rev().map(Op::inverse).collect()),
+        }
+    }
+
+    // the node that should be selected after applying this op
+    pub fn focus(&self) -> Option<NodeID> {
+        match *self {
+            Op::Edit { ref after, .. } => after.first().map(|n| n.id),
+            Op::Insert { ref nodes, .. } => nodes.first().map(|n| n.id),
+            Op::Remove { parent, .. } => Some(parent),
+            Op::AddArrow(from, _) | Op::RemoveArrow(from, _) => Some(from),
+            Op::Group(ref ops) => ops.iter().find_map(Op::focus),
+        }
+    }
+
+    // true if this op only created or edited the given node
+    fn only_touches(&self, node_id: NodeID) -> bool {
+        match *self {
+            Op::Edit { ref after, .. } => after.len() == 1 && after[0].id == node_id,
+            Op::Insert { ref nodes, .. } => nodes.len() == 1 && nodes[0].id == node_id,
+            _ => false,
+        }
+    }
+
+    // snapshots taken while the node existed must not bring it back
+    fn forget_child(&mut self, node_id: NodeID) {
+        let nodes: Vec<&mut Node> = match *self {
+            Op::Edit {
+                ref mut before,
+                ref mut after,
+            } => before.iter_mut().chain(after.iter_mut()).collect(),
+            Op::Insert { ref mut nodes, .. } | Op::Remove { ref mut nodes, .. } => {
+                nodes.iter_mut().collect()
+            }
+            Op::Group(ref mut ops) => {
+                for op in ops {
+                    op.forget_child(node_id);
+                }
+                vec![]
+            }
+            _ => vec![],
+        };
+        for n in nodes {
+            n.children.retain(|&c| c != node_id);
+        }
+    }
+}
+
+pub struct UndoLog {
+    undo: VecDeque<Op>,
+    redo: Vec<Op>,
+    limit: usize,
+    // node currently being typed into, whose keystrokes
+    // are folded into the most recent op
+    typing: Option<NodeID>,
+}
+
+impl Default for UndoLog {
+    fn default() -> UndoLog {
+        UndoLog {
+            undo: VecDeque::new(),
+            redo: vec![],
+            limit: DEFAULT_UNDO_LIMIT,
+            typing: None,
+        }
+    }
+}
+
+impl UndoLog {
+    pub fn record(&mut self, op: Op) {
+        // freshly created nodes are selected for typing
+        self.typing = match op {
+            Op::Insert { ref nodes, .. } if nodes.len() == 1 => Some(nodes[0].id),
+            _ => None,
+        };
+        self.redo.clear();
+        self.undo.push_back(op);
+        while self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+    }
+
+    // record an edit to a single node's text, merging it into the
+    // previous op if we're still typing into the same node
+    pub fn record_typing(&mut self, before: Node, node: Node) {
+        let node_id = node.id;
+        if self.typing == Some(node_id) {
+            if let Some(op) = self.undo.back_mut() {
+                if op.only_touches(node_id) {
+                    match *op {
+                        Op::Edit { ref mut after, .. } => after[0] = node,
+                        Op::Insert { ref mut nodes, .. } => nodes[0] = node,
+                        _ => unreachable!(),
+                    }
+                    self.redo.clear();
+                    return;
+                }
+            }
+        }
+        self.record(Op::Edit {
+            before: vec![before],
+            after: vec![node],
+        });
+        self.typing = Some(node_id);
+    }
+
+    // stop folding keystrokes into the last op
+    pub fn seal(&mut self) { self.typing = None; }
+
+    // if the last op created this node and nothing else happened since,
+    // drop it so that discarding the node leaves no trace in the history
+    pub fn forget_created(&mut self, node_id: NodeID) -> bool {
+        let created = match self.undo.back() {
+            Some(op @ &Op::Insert { .. }) => op.only_touches(node_id),
+            _ => false,
+        };
+        if created {
+            self.undo.pop_back();
+            for op in &mut self.undo {
+                op.forget_child(node_id);
+            }
+            self.redo.clear();
+            self.typing = None;
+        }
+        created
+    }
+
+    // returns the op that reverts the most recent change
+    pub fn undo(&mut self) -> Option<Op> {
+        self.typing = None;
+        let op = self.undo.pop_back()?;
+        self.redo.push(op.clone());
+        Some(op.inverse())
+    }
+
+    // returns the op that reapplies the most recently undone change
+    pub fn redo(&mut self) -> Option<Op> {
+        self.typing = None;
+        let op = self.redo.pop()?;
+        self.undo.push_back(op.clone());
+        Some(op)
+    }
+
+    // what can be undone, oldest first, and what can be redone, next
+    // last, to be saved. no more is kept than a new session would keep.
+    pub fn history(&self) -> (Vec<&Op>, &[Op]) {
+        let skip = self.undo.len().saturating_sub(DEFAULT_UNDO_LIMIT);
+        (self.undo.iter().skip(skip).collect(), &self.redo)
+    }
+
+    // picks up a saved history
+    pub fn from_history(undo: Vec<Op>, redo: Vec<Op>) -> UndoLog {
+        let mut log = UndoLog {
+            undo: undo.into(),
+            redo,
+            ..UndoLog::default()
+        };
+        log.set_limit(DEFAULT_UNDO_LIMIT);
+        log
+    }
+
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        while self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+    }
+}
+
+#[test]
+fn test_undo_redo_bounds() {
+    let mut log = UndoLog::default();
+    log.set_limit(2);
+    for id in 1..=3 {
+        log.record(Op::AddArrow(id, 0));
+    }
+    assert_eq!(log.undo(), Some(Op::RemoveArrow(3, 0)));
+    assert_eq!(log.undo(), Some(Op::RemoveArrow(2, 0)));
+    // the first op fell off the end of the bounded history
+    assert_eq!(log.undo(), None);
+    assert_eq!(log.redo(), Some(Op::AddArrow(2, 0)));
+
+    // recording a new op invalidates what could be redone
+    log.record(Op::AddArrow(4, 0));
+    assert_eq!(log.redo(), None);
+}
+
+#[test]
+fn test_saved_history_is_bounded() {
+    let mut log = UndoLog::default();
+    log.set_limit(DEFAULT_UNDO_LIMIT + 2);
+    for id in 0..DEFAULT_UNDO_LIMIT as u64 + 2 {
+        log.record(Op::AddArrow(id, 0));
+    }
+    log.undo();
+    let (undo, redo) = log.history();
+    assert_eq!(undo.len(), DEFAULT_UNDO_LIMIT);
+    assert_eq!(undo[0], &Op::AddArrow(1, 0));
+    assert_eq!(redo, &[Op::AddArrow(DEFAULT_UNDO_LIMIT as u64 + 1, 0)]);
+
+    let ops = (0..DEFAULT_UNDO_LIMIT as u64 + 1).map(|id| Op::AddArrow(id, 0));
+    let mut log = UndoLog::from_history(ops.collect(), vec![]);
+    for _ in 0..DEFAULT_UNDO_LIMIT {
+        assert!(log.undo().is_some());
+    }
+    assert_eq!(log.undo(), None);
+}
+
+#[test]
+fn test_typing_coalesces() {
+    let mut log = UndoLog::default();
+    let mut node = Node {
+        id: 1,
+        ..Node::default()
+    };
+    log.record(Op::Insert {
+        parent: 0,
+        idx: 0,
+        nodes: vec![node.clone()],
+        arrows: vec![],
+    });
+    let before = node.clone();
+    node.content.push('a');
+    log.record_typing(before, node.clone());
+    assert!(log.forget_created(1));
+    assert_eq!(log.undo(), None);
+}
+
+#[test]
+fn test_forgotten_child_is_not_restored() {
+    let mut log = UndoLog::default();
+    let parent = Node {
+        id: 1,
+        children: vec![2],
+        ..Node::default()
+    };
+    log.record(Op::Edit {
+        before: vec![parent.clone()],
+        after: vec![parent],
+    });
+    log.record(Op::Insert {
+        parent: 1,
+        idx: 0,
+        nodes: vec![Node {
+            id: 2,
+            parent_id: 1,
+            ..Node::default()
+        }],
+        arrows: vec![],
+    });
+    assert!(log.forget_created(2));
+    match log.undo() {
+        Some(Op::Edit { after, .. }) => assert!(after[0].children.is_empty()),
+        other => panic!("unexpected op {:?}", other),
+    }
+}
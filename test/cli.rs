@@ -0,0 +1,99 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    path::Path,
+    process::{Command, Output},
+};
+
+use fs2::FileExt;
+
+const VOID: &str = env!("CARGO_BIN_EXE_void");
+
+fn stdout(out: Output) -> String {
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(out.status.success(), "{}", stderr);
+    String::from_utf8(out.stdout).unwrap()
+}
+
+// a fresh directory for each test's database
+fn db_path(test: &str) -> String {
+    let dir = env::temp_dir().join(format!("void-cli-{}-{}", std::process::id(), test));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join("void.db").into_os_string().into_string().unwrap()
+}
+
+fn remove_db(path: &str) {
+    fs::remove_dir_all(Path::new(path).parent().unwrap()).unwrap();
+}
+
+#[test]
+fn json_export_pipes_into_import() {
+    let path = db_path("pipe");
+    let void = |args: &[&str]| stdout(Command::new(VOID).arg(&path).args(args).output().unwrap());
+    void(&["add", "todo 0"]);
+
+    // both ends of the pipe open the same file at the same time
+    for n in 1..=10 {
+        let pipeline = format!(
+            "{void} {path} export --format json | sed 's/todo {prev}/todo {n}/' | {void} {path} import --format json -",
+            void = VOID,
+            path = path,
+            prev = n - 1,
+            n = n
+        );
+        let out = Command::new("sh").args(["-c", &pipeline]).output();
+        stdout(out.unwrap());
+    }
+    assert!(void(&["ls"]).contains("todo 10"));
+    remove_db(&path);
+}
+
+#[test]
+fn parallel_adds_are_all_kept() {
+    let path = db_path("adds");
+    let children: Vec<_> = (0..20)
+        .map(|n| {
+            Command::new(VOID)
+                .arg(&path)
+                .args(["add", &format!("n{}", n)])
+                .spawn()
+                .unwrap()
+        })
+        .collect();
+    for child in children {
+        stdout(child.wait_with_output().unwrap());
+    }
+    let listed = stdout(Command::new(VOID).arg(&path).arg("ls").output().unwrap());
+    let mut names: Vec<&str> = listed
+        .lines()
+        .filter_map(|l| l.rsplit(['\t', '/']).next())
+        .collect();
+    names.sort();
+    let mut expected: Vec<String> = (0..20).map(|n| format!("n{}", n)).collect();
+    expected.sort();
+    assert_eq!(names, expected);
+    remove_db(&path);
+}
+
+#[test]
+fn subcommands_are_refused_while_the_map_is_open() {
+    let path = db_path("open");
+    let void = |args: &[&str]| Command::new(VOID).arg(&path).args(args).output().unwrap();
+    stdout(void(&["add", "before"]));
+
+    // what an interactive void holds for as long as it runs
+    let lock = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .open(format!("{}.lock", path))
+        .unwrap();
+    lock.lock_exclusive().unwrap();
+    let out = void(&["add", "during"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("Another `void` process"));
+    drop(lock);
+
+    let listed = stdout(void(&["ls"]));
+    assert!(listed.contains("before") && !listed.contains("during"));
+    remove_db(&path);
+}
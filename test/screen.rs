@@ -1,4 +1,4 @@
-use std::{fs::OpenOptions, os::unix::io::AsRawFd};
+use std::{collections::HashSet, fs::OpenOptions, os::unix::io::AsRawFd};
 
 use libc::dup2;
 
@@ -37,6 +37,9 @@ impl Arbitrary for Op {
             Event::Key(Key::Ctrl('n')),
             Event::Key(Key::Ctrl(c)),
             Event::Key(Key::Ctrl(u)),
+            Event::Key(Key::Ctrl('z')),
+            Event::Key(Key::Alt('z')),
+            Event::Key(Key::Alt('c')),
             Event::Key(Key::PageUp),
             Event::Key(Key::PageDown),
             Event::Key(Key::Esc),
@@ -71,6 +74,9 @@ impl Arbitrary for Content {
             "#tagged=dog",
             "#tagged=does_not_exist",
             "#rev",
+            "#sort=prio:desc",
+            "#sort=due",
+            "#sort=alpha:sideways",
             "#limit=0",
             "#limit=1",
             "#limit=2",
@@ -79,11 +85,31 @@ impl Arbitrary for Content {
             "#since=0d",
             "#until=1d",
             "#until=0d",
+            "#due=1d",
+            "#due=none",
+            "#due<3d",
+            "#q=cat|dog",
+            "#q=(cat & !dog",
+            "#q=prio>=3 & !task",
+            "#tagged=q",
+            "#q=q|cat",
+            "#cat/dog",
+            "#tagged=cat #inherit",
             "#n=0",
             "#n=1",
             "#n=100",
             "#plot=done",
             "#plot=open",
+            "#plot=net",
+            "#plot=cumulative",
+            "#plot=burndown #due=3d",
+            "#plot=calendar",
+            "#habit",
+            "#every=3w",
+            "#every=sometimes",
+            "#h=0",
+            "#h=4",
+            "#h=100",
             "#plot=",
             "#plot=InVaLiD",
             "#done",
@@ -170,6 +196,482 @@ fn prop_handle_events(ops: OpVec, dims: (u16, u16)) -> bool {
     true
 }
 
+#[test]
+fn undo_and_redo_restore_edits() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+
+    let keys = vec![Key::Ctrl('n'), Key::Char('a'), Key::Char('b'), Key::Esc];
+    for key in keys {
+        screen.handle_event(Event::Key(key));
+    }
+    let content = |screen: &Screen| {
+        let mut content: Vec<String> = screen.nodes.values().map(|n| n.content.clone()).collect();
+        content.sort();
+        content
+    };
+    assert_eq!(content(&screen), vec!["ab", "home"]);
+
+    // creating a node and typing into it is undone in one step
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(content(&screen), vec!["home"]);
+
+    screen.handle_event(Event::Key(Key::Alt('z')));
+    assert_eq!(content(&screen), vec!["ab", "home"]);
+
+    // redo selects the restored node, so keep typing into it
+    screen.handle_event(Event::Key(Key::Char('c')));
+    assert_eq!(content(&screen), vec!["abc", "home"]);
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(content(&screen), vec!["ab", "home"]);
+
+    // the history is saved along with everything else
+    let loaded = vec![
+        deserialize_screen(serialize_screen(&screen)).unwrap(),
+        screen_from_json(&screen_to_json(&screen)).unwrap(),
+    ];
+    for mut loaded in loaded {
+        loaded.is_test = true;
+        loaded.dims = (80, 24);
+        loaded.handle_event(Event::Key(Key::Alt('z')));
+        assert_eq!(content(&loaded), vec!["abc", "home"]);
+        loaded.handle_event(Event::Key(Key::Ctrl('z')));
+        loaded.handle_event(Event::Key(Key::Ctrl('z')));
+        assert_eq!(content(&loaded), vec!["home"]);
+    }
+}
+
+#[test]
+fn dates_read_from_a_parent_are_undone_after_its_new_child() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+
+    screen.handle_event(Event::Key(Key::Ctrl('n')));
+    for c in "a [3d]\t".chars() {
+        screen.handle_event(Event::Key(Key::Char(c)));
+    }
+    let a = screen.nodes.values().find(|n| n.content == "a").unwrap().id;
+    assert!(screen.nodes[&a].meta.due.is_some());
+    assert_eq!(screen.nodes[&a].children.len(), 1);
+
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert!(screen.nodes[&a].children.is_empty());
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(screen.nodes[&a].content, "a [3d]");
+    assert!(screen.nodes[&a].children.is_empty());
+    screen.assert_node_consistency();
+    screen.arrange();
+}
+
+#[test]
+fn deleted_nodes_are_kept_in_the_trash() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+
+    let keys = vec![
+        Key::Ctrl('n'),
+        Key::Char('a'),
+        Key::Char('b'),
+        Key::Char('\t'),
+        Key::Char('c'),
+        Key::Alt('P'),
+        Key::Delete,
+    ];
+    for key in keys {
+        screen.handle_event(Event::Key(key));
+    }
+    assert_eq!(screen.nodes.len(), 1);
+    assert_eq!(screen.trash.len(), 1);
+    assert_eq!(screen.trash[0].nodes.len(), 2);
+
+    // the trash survives a save and load
+    let loaded = deserialize_screen(serialize_screen(&screen)).unwrap();
+    let content: Vec<&str> = loaded.trash[0]
+        .nodes
+        .iter()
+        .map(|n| n.content.as_str())
+        .collect();
+    assert_eq!(content, vec!["ab", "c"]);
+
+    // undoing the delete takes the node back out of the trash
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(screen.nodes.len(), 3);
+    assert!(screen.trash.is_empty());
+}
+
+#[test]
+fn headless_edits_by_path() {
+    let mut screen = Screen::default();
+    let work = screen.add_node(0, "work #task".to_owned()).unwrap();
+    let today = screen.add_node(work, "today".to_owned()).unwrap();
+    assert_eq!(screen.resolve("home/work/today"), Some(today));
+    assert_eq!(screen.resolve("work #task"), Some(work));
+    assert_eq!(screen.resolve(&today.to_string()), Some(today));
+    assert_eq!(screen.resolve("work/tomorrow"), None);
+    assert_eq!(screen.node_path(today), "home/work #task/today");
+
+    assert!(screen.finish_node(today));
+    assert!(!screen.finish_node(today));
+    assert!(screen.nodes[&today].meta.finish_time.is_some());
+
+    assert!(!screen.remove_node(0));
+    assert!(screen.remove_node(work));
+    assert_eq!(screen.nodes.len(), 1);
+    assert_eq!(screen.trash.len(), 1);
+    screen.assert_node_consistency();
+}
+
+#[test]
+fn queries_without_a_terminal() {
+    let mut screen = Screen::default();
+    let work = screen.add_node(0, "project #work".to_owned()).unwrap();
+    let first = screen.add_node(work, "first".to_owned()).unwrap();
+    let second = screen.add_node(work, "second".to_owned()).unwrap();
+    screen.add_node(0, "unrelated".to_owned()).unwrap();
+    screen.finish_node(first);
+
+    // only the tagged nodes, as a query node lists them, unless
+    // the subtrees below them are asked for
+    let query = Query::parse("#tagged=work #open");
+    assert_eq!(screen.query(&query, false), vec![work]);
+    assert_eq!(screen.query(&query, true), vec![work, second]);
+    let query = Query::parse("#tagged=work #done");
+    assert_eq!(screen.query(&query, false), vec![]);
+    assert_eq!(screen.query(&query, true), vec![first]);
+    let query = Query::parse("#open #rev #limit=1");
+    assert_eq!(screen.query(&query, false).len(), 1);
+}
+
+#[test]
+fn due_dates_are_set_saved_and_queried() {
+    let mut screen = Screen::default();
+    let soon = screen.add_node(0, "call bob #due=1d".to_owned()).unwrap();
+    let later = screen
+        .add_node(0, "renew #due=2w #home".to_owned())
+        .unwrap();
+    let bad = screen.add_node(0, "ship #due=whenever".to_owned()).unwrap();
+    assert_eq!(screen.nodes[&soon].content, "call bob");
+    assert_eq!(screen.nodes[&later].content, "renew #home");
+    assert_eq!(screen.nodes[&bad].meta.due, None);
+    assert_eq!(
+        screen.nodes[&bad].due_tag_error().map(|e| e.to_string()),
+        Some("can't read \"whenever\" as a date".to_owned())
+    );
+    assert_eq!(screen.nodes[&soon].due_tag_error(), None);
+    assert!(screen.nodes[&soon].meta.due > Some(screen.nodes[&soon].meta.ctime));
+
+    let data = serialize_screen(&screen);
+    let screen = deserialize_screen(data).unwrap();
+    assert_eq!(screen.query(&Query::parse("#due<3d"), false), vec![soon]);
+    assert_eq!(screen.query(&Query::parse("#due>3d"), false), vec![later]);
+}
+
+#[test]
+fn measurements_are_recorded_saved_and_undone() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    let weight = screen
+        .add_node(0, "weight #metric=weight".to_owned())
+        .unwrap();
+    let plain = screen.add_node(0, "plain".to_owned()).unwrap();
+    assert_eq!(screen.metric_name(weight), Some("weight".to_owned()));
+    assert!(screen.record_measurement(plain, 100, 1.0).is_err());
+    assert!(screen.record_measurement(weight, 100, f64::NAN).is_err());
+    screen.record_measurement(weight, 300, 71.9).unwrap();
+    screen.record_measurement(weight, 100, 72.4).unwrap();
+
+    let data = serialize_screen(&screen);
+    let loaded = deserialize_screen(data).unwrap();
+    let meta = &loaded.nodes[&weight].meta;
+    assert_eq!(
+        meta.measurements_between(Some(50), Some(200)),
+        &[Measurement {
+            time: 100,
+            value: 72.4
+        }]
+    );
+    assert_eq!(meta.measurements_between(None, None).len(), 2);
+
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(screen.nodes[&weight].meta.measurements.len(), 1);
+}
+
+#[test]
+fn habits_are_checked_off_each_period() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    let run = screen
+        .add_node(0, "run #habit #every=2d".to_owned())
+        .unwrap();
+    let bad = screen
+        .add_node(0, "floss #habit #every=often".to_owned())
+        .unwrap();
+    assert_eq!(
+        screen.nodes[&run].habit_period(),
+        Some(Ok(60 * 60 * 24 * 2))
+    );
+    assert!(screen.nodes[&bad].habit_period().unwrap().is_err());
+
+    // checking it off leaves it open for next time
+    assert!(screen.finish_node(run));
+    assert!(screen.finish_node(run));
+    let node = &screen.nodes[&run];
+    assert!(!node.stricken && node.meta.finish_time.is_none());
+    assert_eq!(node.meta.completions.len(), 2);
+
+    let data = serialize_screen(&screen);
+    let loaded = deserialize_screen(data).unwrap();
+    assert_eq!(loaded.nodes[&run].meta.completions.len(), 2);
+
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(screen.nodes[&run].meta.completions.len(), 1);
+}
+
+#[test]
+fn boolean_tag_queries() {
+    let mut screen = Screen::default();
+    let work = screen.add_node(0, "#work #prio=5".to_owned()).unwrap();
+    let oss = screen
+        .add_node(0, "#oss #blocked #prio=4".to_owned())
+        .unwrap();
+    let chore = screen.add_node(work, "#chore #prio=1".to_owned()).unwrap();
+
+    let query = Query::parse("#q=(work|oss|chore) & !blocked & prio>=3");
+    assert_eq!(screen.query(&query, false), vec![work]);
+    let query = Query::parse("#q=prio<5 #open");
+    assert_eq!(screen.query(&query, false), vec![oss, chore]);
+    assert_eq!(Query::parse("#q=prio>= #open").errors.len(), 1);
+}
+
+#[test]
+fn query_nodes_list_their_matches() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+    let work = screen.add_node(0, "#work".to_owned()).unwrap();
+    let first = screen.add_node(work, "first #todo".to_owned()).unwrap();
+    let second = screen.add_node(0, "second #todo".to_owned()).unwrap();
+    let dashboard = screen
+        .add_node(0, "todos #tagged=todo #sort=alpha:desc".to_owned())
+        .unwrap();
+    screen.arrange();
+    screen.draw();
+
+    // matches are drawn below the query node without joining the tree
+    let (x, y) = *screen.drawn_at(dashboard).unwrap();
+    let shown: Vec<NodeID> = (1..=2)
+        .map(|dy| *screen.lookup((x + 3, y + dy)).unwrap())
+        .collect();
+    assert!(shown.iter().all(|id| !screen.nodes.contains_key(id)));
+    assert!(screen.nodes[&dashboard].children.is_empty());
+
+    // clicking a match selects the real node
+    let click = MouseEvent::Press(MouseButton::Left, x + 3, y + 1);
+    screen.handle_event(Event::Mouse(click));
+    screen.handle_event(Event::Mouse(MouseEvent::Release(x + 3, y + 1)));
+    assert!(screen.nodes[&second].selected);
+    assert!(!screen.nodes[&first].selected);
+    screen.assert_node_consistency();
+}
+
+#[test]
+fn the_calendar_pushes_the_map_down() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+    let top = screen.add_node(0, "top".to_owned()).unwrap();
+    screen.arrange();
+    screen.draw();
+    let (x, y) = *screen.drawn_at(top).unwrap();
+    let click = |screen: &mut Screen, y| {
+        screen.handle_event(Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)));
+        screen.handle_event(Event::Mouse(MouseEvent::Release(x, y)));
+    };
+
+    // clicks on the calendar don't reach the node it would cover
+    screen.handle_event(Event::Key(Key::Alt('c')));
+    screen.draw();
+    click(&mut screen, y);
+    assert!(!screen.nodes[&top].selected);
+    assert_eq!(screen.nodes.len(), 2);
+    click(&mut screen, y + 8);
+    assert!(screen.nodes[&top].selected);
+}
+
+#[test]
+fn query_cycles_are_cut_short() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 40);
+    let a = screen.add_node(0, "a #x #tagged=y".to_owned()).unwrap();
+    screen.add_node(0, "b #y #tagged=x".to_owned()).unwrap();
+    let c = screen.add_node(0, "c #z #tagged=z".to_owned()).unwrap();
+    screen.arrange();
+    screen.draw();
+
+    // a lists b, which lists a again but stops there
+    let rows_below = |screen: &Screen, id| {
+        let (x, y) = *screen.drawn_at(id).unwrap();
+        (1..)
+            .take_while(|dy| {
+                screen
+                    .lookup((x, y + dy))
+                    .is_some_and(|id| !screen.nodes.contains_key(id))
+            })
+            .count()
+    };
+    assert_eq!(rows_below(&screen, a), 2);
+    assert_eq!(rows_below(&screen, c), 1);
+}
+
+#[test]
+fn tags_are_inherited() {
+    let mut screen = Screen::default();
+    let proj = screen
+        .add_node(0, "void #proj/void #prio=3".to_owned())
+        .unwrap();
+    let ui = screen.add_node(proj, "ui #prio=1".to_owned()).unwrap();
+    let button = screen.add_node(ui, "button".to_owned()).unwrap();
+    screen.add_node(0, "elsewhere #prio=2".to_owned()).unwrap();
+
+    assert_eq!(
+        screen.effective_tags(button),
+        vec!["prio", "prio=1", "proj", "proj/void"]
+    );
+    let query = Query::parse("#q=proj & prio<2 #inherit");
+    assert_eq!(screen.query(&query, false), vec![ui, button]);
+    let query = Query::parse("#tagged=proj");
+    assert_eq!(
+        query.tagged_nodes(&screen.tag_db, &screen.nodes),
+        Some(vec![proj])
+    );
+}
+
+#[test]
+fn tags_are_renamed_and_merged() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    let a = screen
+        .add_node(0, "a #wrok #prio=2 #x #x".to_owned())
+        .unwrap();
+    let b = screen
+        .add_node(0, "b #work #wrok #wrok=x #wrok/sub".to_owned())
+        .unwrap();
+    let c = screen
+        .add_node(0, "c #tagged=wrok #sort=wrok:desc".to_owned())
+        .unwrap();
+    screen.add_node(0, "d #wroking".to_owned()).unwrap();
+    let e = screen
+        .add_node(0, "list #q=wrok | home #open".to_owned())
+        .unwrap();
+    let f = screen
+        .add_node(0, "z  two   spaces #work #wrok".to_owned())
+        .unwrap();
+
+    assert_eq!(
+        screen.rename_tag_preview("wrok", "work", false),
+        Err("#work is already in use, merge instead".to_owned())
+    );
+    assert!(screen.rename_tag_preview("wrok", "wrok/sub", true).is_err());
+    assert_eq!(
+        screen.rename_tag_preview("wrok", "work", true),
+        Ok(vec![a, b, c, e, f])
+    );
+    assert_eq!(screen.rename_tag("wrok", "work", true), Ok(5));
+    // repeated tags that have nothing to do with the merge are kept
+    assert_eq!(screen.nodes[&a].content, "a #work #prio=2 #x #x");
+    assert_eq!(screen.nodes[&b].content, "b #work #work=x #work/sub");
+    assert_eq!(screen.nodes[&c].content, "c #tagged=work #sort=work:desc");
+    assert_eq!(screen.nodes[&e].content, "list #q=work | home #open");
+    // only the duplicate is dropped
+    assert_eq!(screen.nodes[&f].content, "z  two   spaces #work");
+    assert!(screen.tag_db.tag_to_nodes("wrok").is_empty());
+    assert_eq!(screen.tag_db.tag_to_nodes("work/sub"), vec![b]);
+    assert!(screen.rename_tag("wrok", "work", true).is_err());
+
+    // the whole rename is undone in one step
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert_eq!(screen.nodes[&a].content, "a #wrok #prio=2 #x #x");
+    assert_eq!(screen.tag_db.tag_to_nodes("work"), vec![b, f]);
+}
+
+#[test]
+fn tab_completes_tags() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 24);
+    screen
+        .add_node(0, "a #client_143 #prio=2".to_owned())
+        .unwrap();
+    screen.add_node(0, "b #clinic #prio=5".to_owned()).unwrap();
+    screen.add_node(0, "w #work #workshop".to_owned()).unwrap();
+
+    let type_keys = |screen: &mut Screen, keys: &str| {
+        for c in keys.chars() {
+            screen.handle_event(Event::Key(Key::Char(c)));
+        }
+    };
+    screen.handle_event(Event::Key(Key::Ctrl('n')));
+    type_keys(&mut screen, "c #cli\t");
+    let content = |screen: &Screen| {
+        let node = screen.nodes.values().find(|n| n.content.starts_with("c "));
+        node.unwrap().content.clone()
+    };
+    assert_eq!(content(&screen), "c #client_143");
+    // Tab again moves on to the next candidate, and then wraps around
+    type_keys(&mut screen, "\t");
+    assert_eq!(content(&screen), "c #clinic");
+    type_keys(&mut screen, "\t");
+    assert_eq!(content(&screen), "c #client_143");
+
+    type_keys(&mut screen, " #prio=\t\t");
+    assert_eq!(content(&screen), "c #client_143 #prio=5");
+
+    // without anything to complete Tab still creates a child
+    let count = screen.nodes.len();
+    type_keys(&mut screen, " #new\t");
+    assert_eq!(screen.nodes.len(), count + 1);
+
+    // and so it does after a complete tag that others start with
+    type_keys(&mut screen, "d #work\t");
+    assert_eq!(screen.nodes.len(), count + 2);
+    let node = screen.nodes.values().find(|n| n.content.starts_with("d "));
+    assert_eq!(node.unwrap().content, "d #work");
+}
+
+#[test]
+fn markdown_round_trip() {
+    let markdown = "- project #task
+  - [x] first
+
+    with some notes
+
+  - [ ] second
+- elsewhere
+";
+    let mut screen = Screen::default();
+    let outlines = import(markdown, "markdown").unwrap();
+    let roots = screen.graft(0, outlines).unwrap();
+    assert_eq!(roots.len(), 2);
+    screen.assert_node_consistency();
+    assert_eq!(
+        export(&screen, 0, "markdown").unwrap(),
+        markdown.replace("- project", "- [ ] project")
+    );
+
+    // the whole import is a single step to undo
+    screen.is_test = true;
+    screen.handle_event(Event::Key(Key::Ctrl('z')));
+    assert!(roots.iter().all(|id| !screen.nodes.contains_key(id)));
+    screen.assert_node_consistency();
+    screen.handle_event(Event::Key(Key::Alt('z')));
+    assert!(roots.iter().all(|id| screen.nodes.contains_key(id)));
+    screen.assert_node_consistency();
+}
+
 #[test]
 fn qc_input_events_dont_crash_void() {
     // redirect stdout to quickcheck.out to make travis happy
@@ -189,3 +691,36 @@ fn qc_input_events_dont_crash_void() {
         .max_tests(1_000_000)
         .quickcheck(prop_handle_events as fn(OpVec, (u16, u16)) -> bool);
 }
+
+#[test]
+fn charts_are_arranged_apart_from_siblings() {
+    let mut screen = Screen::default();
+    screen.is_test = true;
+    screen.dims = (80, 40);
+    let done = screen.add_node(0, "done #chore".to_owned()).unwrap();
+    screen.finish_node(done);
+    let chart = screen
+        .add_node(0, "#plot=done #tagged=chore #h=4".to_owned())
+        .unwrap();
+    let sibling = screen.add_node(0, "sibling".to_owned()).unwrap();
+    screen.arrange();
+    screen.draw();
+
+    let cells = |id| {
+        let screen = &screen;
+        (0..screen.dims.0)
+            .flat_map(move |x| (0..screen.dims.1).map(move |y| (x, y)))
+            .filter(move |&coords| screen.lookup(coords) == Some(&id))
+    };
+    let rows: HashSet<u16> = cells(chart).map(|(_, y)| y).collect();
+    assert!(rows.len() > 4);
+    let left = cells(chart).map(|(x, _)| x).min().unwrap();
+    let right = cells(chart).map(|(x, _)| x).max().unwrap();
+    let (top, bottom) = (*rows.iter().min().unwrap(), *rows.iter().max().unwrap());
+    let (x, y) = *screen.drawn_at(sibling).unwrap();
+
+    // side by side, without the sibling drawn over any chart row
+    assert_eq!(y, top);
+    assert!(x > right);
+    assert!(cells(sibling).all(|(x, y)| x < left || x > right || y < top || y > bottom));
+}